@@ -0,0 +1,173 @@
+/// Test that a balance provides a reference to the account.
+#[ink::test]
+fn providers_follow_balance() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+
+    let account = accounts.bob;
+    assert_eq!(contract.account_refs(account), AccountRefs::default());
+
+    // Endowment adds a provider
+    contract.mint(account, 100).unwrap();
+    assert_eq!(contract.providers(account), 1);
+    assert_eq!(contract.consumers(account), 0);
+
+    // Reaping removes it
+    contract
+        .burn_from(
+            account,
+            100,
+            Preservation::Expendable,
+            Precision::Exact,
+            Fortitude::Polite,
+        )
+        .unwrap();
+    assert_eq!(contract.providers(account), 0);
+}
+
+/// Test that holds, locks and freezes consume a reference to the account.
+#[ink::test]
+fn consumers_follow_holds_and_freezes() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+
+    let account = accounts.bob;
+    let reason = *b"staking_";
+    contract.mint(account, 100).unwrap();
+
+    contract.hold(reason, account, 20).unwrap();
+    assert_eq!(contract.consumers(account), 1);
+    contract.set_freeze(reason, account, 30).unwrap();
+    assert_eq!(contract.consumers(account), 1);
+
+    contract
+        .release(reason, account, 20, Precision::Exact)
+        .unwrap();
+    assert_eq!(contract.consumers(account), 1);
+    contract.thaw(reason, account).unwrap();
+    assert_eq!(contract.consumers(account), 0);
+    assert!(contract.can_dec_provider(account));
+
+    // A lock consumes a reference until it is removed
+    contract.set_lock(account, *b"testlock", 10).unwrap();
+    assert_eq!(contract.consumers(account), 1);
+    assert!(!contract.can_dec_provider(account));
+    contract.remove_lock(account, *b"testlock").unwrap();
+    assert_eq!(contract.consumers(account), 0);
+}
+
+/// Test that an account with consumers cannot be reaped, even when expendable.
+#[ink::test]
+fn expendable_keeps_account_with_consumers() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+
+    let account = accounts.bob;
+    let other = accounts.charlie;
+    contract.mint(account, 100).unwrap();
+    contract.inc_consumers(account).unwrap();
+    assert!(!contract.can_dec_provider(account));
+
+    assert_eq!(
+        contract.reducible_balance(account, Preservation::Expendable, Fortitude::Polite),
+        90
+    );
+    assert_eq!(
+        contract.can_withdraw(account, 100),
+        WithdrawConsequence::WouldDie
+    );
+    assert_eq!(
+        contract.burn_from(
+            account,
+            100,
+            Preservation::Expendable,
+            Precision::Exact,
+            Fortitude::Force,
+        ),
+        Err(Error::Expendability)
+    );
+
+    test::set_caller::<ink::env::DefaultEnvironment>(account);
+    assert_eq!(contract.transfer(other, 100), Err(Error::Expendability));
+    assert_eq!(contract.transfer(other, 90), Ok(()));
+    assert_eq!(contract.balance(account), 10);
+
+    // Once the consumer is gone the account can be reaped
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    contract.dec_consumers(account).unwrap();
+    test::set_caller::<ink::env::DefaultEnvironment>(account);
+    assert_eq!(contract.transfer(other, 10), Ok(()));
+    assert_eq!(contract.providers(account), 0);
+}
+
+/// Test that `Protect` only keeps the account alive when the balance is its only provider.
+#[ink::test]
+fn protect_with_other_provider() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+
+    let account = accounts.bob;
+    contract.mint(account, 100).unwrap();
+
+    assert_eq!(
+        contract.reducible_balance(account, Preservation::Protect, Fortitude::Polite),
+        90
+    );
+
+    // Another provider keeps the account alive without the balance
+    contract.inc_providers(account).unwrap();
+    assert_eq!(contract.providers(account), 2);
+    assert_eq!(
+        contract.reducible_balance(account, Preservation::Protect, Fortitude::Polite),
+        100
+    );
+    assert_eq!(
+        contract.reducible_balance(account, Preservation::Preserve, Fortitude::Polite),
+        90
+    );
+
+    test::set_caller::<ink::env::DefaultEnvironment>(account);
+    assert_eq!(
+        contract.transfer_with_preservation(accounts.charlie, 100, Preservation::Protect),
+        Ok(())
+    );
+    assert_eq!(contract.balance(account), 0);
+    assert_eq!(contract.providers(account), 1);
+}
+
+/// Test the preconditions of the reference counting messages.
+#[ink::test]
+fn refcount_preconditions() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+
+    let account = accounts.bob;
+
+    // Consumers need a provider
+    assert_eq!(contract.inc_consumers(account), Err(Error::NoProviders));
+    contract.inc_providers(account).unwrap();
+    contract.inc_consumers(account).unwrap();
+
+    // The last provider cannot be removed while consumers remain
+    assert_eq!(contract.dec_providers(account), Err(Error::ConsumerRemaining));
+    contract.dec_consumers(account).unwrap();
+    contract.dec_providers(account).unwrap();
+    assert_eq!(contract.dec_providers(account), Err(Error::Underflow));
+
+    // Sufficients are counted independently
+    contract.inc_sufficients(account).unwrap();
+    assert_eq!(contract.sufficients(account), 1);
+    contract.dec_sufficients(account).unwrap();
+    assert_eq!(contract.account_refs(account), AccountRefs::default());
+
+    // Only the owner can change reference counters
+    test::set_caller::<ink::env::DefaultEnvironment>(account);
+    assert_eq!(contract.inc_providers(account), Err(Error::NotAllowed));
+    assert_eq!(contract.inc_consumers(account), Err(Error::NotAllowed));
+    assert_eq!(contract.inc_sufficients(account), Err(Error::NotAllowed));
+}
@@ -25,7 +25,7 @@ fn settle_debt_zero() {
     let account = accounts.bob;
     contract.mint(account, 100).unwrap();
 
-    let debt = DebtImbalance { amount: 0 };
+    let debt = DebtImbalance::zero();
     let remaining = contract
         .settle_debt(account, debt, Preservation::Expendable)
         .unwrap();
@@ -43,7 +43,7 @@ fn settle_debt_partial() {
     contract.mint(account, 50).unwrap();
 
     // Try to settle more debt than available (with Preserve)
-    let debt = DebtImbalance { amount: 60 };
+    let (_credit, debt) = contract.pair(60).unwrap();
     let remaining = contract
         .settle_debt(account, debt, Preservation::Preserve)
         .unwrap();
@@ -389,17 +389,32 @@ fn burn_from_force() {
 
     let account = accounts.bob;
     contract.mint(account, 100).unwrap();
+    contract.set_lock(account, *b"testlock", 50).unwrap();
 
+    // Polite burns respect the lock
+    assert_eq!(
+        contract.burn_from(
+            account,
+            60,
+            Preservation::Expendable,
+            Precision::Exact,
+            Fortitude::Polite,
+        ),
+        Err(Error::LiquidityRestrictions)
+    );
+
+    // Forced burns may dip into locked funds
     let result = contract
         .burn_from(
             account,
-            10,
+            60,
             Preservation::Expendable,
             Precision::Exact,
             Fortitude::Force,
         )
         .unwrap();
-    assert_eq!(result, 10);
+    assert_eq!(result, 60);
+    assert_eq!(contract.balance(account), 40);
 }
 
 /// Test burn_from leaving dust with Expendable
@@ -467,7 +482,7 @@ fn set_balance_same() {
     let account = accounts.bob;
     contract.mint(account, 50).unwrap();
 
-    let result = contract.set_balance(account, 50);
+    let result = contract.set_balance(account, 50).unwrap();
     assert_eq!(result, 50);
 }
 
@@ -482,7 +497,7 @@ fn set_balance_increase_overflow() {
     contract.mint(account, Balance::MAX - 10).unwrap();
 
     // Try to set balance that would overflow the account balance
-    let result = contract.set_balance(account, Balance::MAX);
+    let result = contract.set_balance(account, Balance::MAX).unwrap();
     // set_balance saturates on overflow, so it should succeed
     assert_eq!(result, Balance::MAX);
     assert_eq!(contract.balance(account), Balance::MAX);
@@ -499,7 +514,7 @@ fn set_balance_decrease_overflow() {
     contract.mint(account, 100).unwrap();
 
     // Normal decrease
-    let result = contract.set_balance(account, 50);
+    let result = contract.set_balance(account, 50).unwrap();
     assert_eq!(result, 50);
     assert_eq!(contract.balance(account), 50);
 }
@@ -540,6 +555,158 @@ fn transfer_with_lock() {
     assert_eq!(result, Err(Error::LiquidityRestrictions));
 }
 
+/// Test a fee-only lock does not block transfers
+#[ink::test]
+fn transfer_with_fee_lock() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+
+    let from = accounts.bob;
+    let to = accounts.charlie;
+    contract.mint(from, 100).unwrap();
+
+    let reasons = WithdrawReasons::FEE | WithdrawReasons::TIP;
+    contract
+        .set_lock_with_reasons(from, *b"feelock_", 80, reasons)
+        .unwrap();
+    assert_eq!(contract.account(from).frozen, 80);
+    assert_eq!(contract.can_withdraw(from, 30), WithdrawConsequence::Frozen);
+
+    test::set_caller::<ink::env::DefaultEnvironment>(from);
+    assert_eq!(contract.transfer(to, 60), Ok(()));
+    assert_eq!(contract.balance(from), 40);
+    assert_eq!(contract.balance(to), 60);
+}
+
+/// Test a transfer lock blocks transfers but not reserves in the legacy model
+#[ink::test]
+fn reserve_with_transfer_lock() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = BalancesContract::new_with_legacy_freezes(10, 5);
+
+    let account = accounts.bob;
+    contract.mint(account, 100).unwrap();
+    contract
+        .set_lock_with_reasons(account, *b"govlock_", 80, WithdrawReasons::TRANSFER)
+        .unwrap();
+
+    test::set_caller::<ink::env::DefaultEnvironment>(account);
+    assert_eq!(
+        contract.transfer(accounts.charlie, 30),
+        Err(Error::LiquidityRestrictions)
+    );
+
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    assert_eq!(contract.reserve(account, 60), Ok(()));
+    assert_eq!(contract.account(account).reserved, 60);
+}
+
+/// Test a lock with the reserve reason prevents reserving in the legacy model
+#[ink::test]
+fn reserve_with_reserve_lock() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = BalancesContract::new_with_legacy_freezes(10, 5);
+
+    let account = accounts.bob;
+    contract.mint(account, 100).unwrap();
+    contract
+        .set_lock_with_reasons(account, *b"govlock_", 80, WithdrawReasons::RESERVE)
+        .unwrap();
+
+    assert!(!contract.can_reserve(account, 60));
+    assert_eq!(
+        contract.reserve(account, 60),
+        Err(Error::InsufficientBalance)
+    );
+    assert_eq!(contract.reserve(account, 20), Ok(()));
+    assert_eq!(contract.account(account).reserved, 20);
+}
+
+/// Test setting a lock without reasons removes it
+#[ink::test]
+fn set_lock_with_empty_reasons() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+
+    let account = accounts.bob;
+    contract.mint(account, 100).unwrap();
+    contract.set_lock(account, *b"testlock", 50).unwrap();
+    contract
+        .set_lock_with_reasons(account, *b"testlock", 50, WithdrawReasons::empty())
+        .unwrap();
+    assert_eq!(contract.account(account).frozen, 0);
+}
+
+/// Test extend_lock keeps the larger amount and unions the reasons
+#[ink::test]
+fn extend_lock_merges() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+
+    let account = accounts.bob;
+    contract.mint(account, 100).unwrap();
+    contract
+        .extend_lock(account, *b"testlock", 50, WithdrawReasons::FEE)
+        .unwrap();
+    contract
+        .extend_lock(account, *b"testlock", 30, WithdrawReasons::TRANSFER)
+        .unwrap();
+
+    let locks = contract.locks(account);
+    assert_eq!(locks.len(), 1);
+    assert_eq!(locks[0].amount, 50);
+    assert_eq!(locks[0].reasons, WithdrawReasons::FEE | WithdrawReasons::TRANSFER);
+    assert_eq!(contract.account(account).frozen, 50);
+
+    // Zero amounts leave the lock untouched
+    contract
+        .extend_lock(account, *b"testlock", 0, WithdrawReasons::all())
+        .unwrap();
+    assert_eq!(contract.locks(account), locks);
+}
+
+/// Test extend_lock respects the lock limit
+#[ink::test]
+fn extend_lock_too_many_locks() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 1, None);
+
+    let account = accounts.bob;
+    contract.mint(account, 100).unwrap();
+    contract.set_lock(account, *b"lock0001", 10).unwrap();
+    assert_eq!(
+        contract.extend_lock(account, *b"lock0002", 20, WithdrawReasons::all()),
+        Err(Error::TooManyLocks)
+    );
+}
+
+/// Test reducing a lock emits an event and lowers the frozen balance
+#[ink::test]
+fn set_lock_reduce_emits_unlocked() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+
+    let account = accounts.bob;
+    contract.mint(account, 100).unwrap();
+    contract.set_lock(account, *b"testlock", 50).unwrap();
+
+    let before = test::recorded_events().count();
+    contract.set_lock(account, *b"testlock", 20).unwrap();
+    assert_eq!(test::recorded_events().count(), before + 1);
+    assert_eq!(contract.account(account).frozen, 20);
+
+    // Setting the same amount again changes nothing
+    contract.set_lock(account, *b"testlock", 20).unwrap();
+    assert_eq!(test::recorded_events().count(), before + 1);
+}
+
 /// Test reserve with zero
 #[ink::test]
 fn reserve_zero() {
@@ -819,7 +986,7 @@ fn write_balance_overflow_increase() {
     let account = accounts.bob;
     // Set the account to a high value, but keep total issuance manageable
     contract.set_total_issuance(Balance::MAX - 50).unwrap();
-    contract.set_balance(account, Balance::MAX - 100);
+    contract.set_balance(account, Balance::MAX - 100).unwrap();
 
     // Now try to increase beyond max - the increase would overflow total issuance
     let result = contract.write_balance(account, Balance::MAX);
@@ -965,7 +1132,8 @@ fn transfer_preserve_would_leave_dust() {
 fn reserve_with_frozen_balance() {
     let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
     test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
-    let mut contract = new_contract(10, 5, None);
+    // Only the legacy model lets freezes restrict reserves
+    let mut contract = BalancesContract::new_with_legacy_freezes(10, 5);
 
     let account = accounts.bob;
     contract.mint(account, 100).unwrap();
@@ -1003,7 +1171,7 @@ fn resolve_credit_zero_test() {
     let mut contract = new_contract(10, 5, None);
 
     let account = accounts.bob;
-    let credit = CreditImbalance { amount: 0 };
+    let credit = CreditImbalance::zero();
 
     let result = contract.resolve_credit(account, credit);
     assert_eq!(result, Ok(()));
@@ -1035,7 +1203,7 @@ fn settle_debt_best_effort_remaining() {
     contract.mint(account, 30).unwrap();
 
     // Create debt larger than can be settled
-    let debt = DebtImbalance { amount: 100 };
+    let (_credit, debt) = contract.pair(100).unwrap();
 
     // Settle with Expendable (best effort)
     let remaining = contract
@@ -1081,9 +1249,9 @@ fn can_withdraw_exact_ed() {
         .mint(account, contract.existential_deposit())
         .unwrap();
 
-    // Withdraw full ED should succeed (goes to zero, not dust)
+    // Withdrawing the full ED reduces the account to zero, leaving no dust
     let result = contract.can_withdraw(account, contract.existential_deposit());
-    assert_eq!(result, WithdrawConsequence::Success);
+    assert_eq!(result, WithdrawConsequence::ReducedToZero(0));
 }
 
 /// Test transfer to self (should be a no-op)
@@ -1147,10 +1315,10 @@ fn resolve_credit_nonzero() {
 
     let account = accounts.bob;
 
-    // Create a credit imbalance
-    let credit = CreditImbalance { amount: 50 };
+    // Issue a credit imbalance
+    let credit = contract.issue(50).unwrap();
 
-    // Resolve it - this should mint tokens (line 374)
+    // Resolve it - the issued tokens are deposited into the account
     contract.resolve_credit(account, credit).unwrap();
 
     assert_eq!(contract.balance(account), 50);
@@ -1298,8 +1466,8 @@ fn write_balance_increase_between_dust_values() {
     // Manually set up an account with balance below ED (simulating orphaned dust)
     // This bypasses normal safeguards to test the edge case
     contract.set_total_issuance(1).unwrap();
-    let mut acc_data = AccountData { free: 1, reserved: 0, frozen: 0 };
-    contract.accounts.insert(account, &acc_data);
+    let acc_data = AccountData { free: 1, reserved: 0, frozen: 0 };
+    contract.accounts.insert((NATIVE_ASSET, account), &acc_data);
     
     // Now use write_balance to increase from 1 to 2 (both below ED of 10)
     // This should hit line 512
@@ -1308,4 +1476,26 @@ fn write_balance_increase_between_dust_values() {
     // Should return dust since 2 < ED
     assert_eq!(result, Ok(Some(2)));
     assert_eq!(contract.balance(account), 0);
-}
\ No newline at end of file
+}
+/// Test changing a lock below the largest one still emits an event for that lock
+#[ink::test]
+fn smaller_lock_change_emits_events() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+
+    let account = accounts.bob;
+    contract.mint(account, 100).unwrap();
+    contract.set_lock(account, *b"biglock_", 50).unwrap();
+
+    let before = test::recorded_events().count();
+    contract.set_lock(account, *b"smallock", 20).unwrap();
+    assert_eq!(test::recorded_events().count(), before + 1);
+    contract.set_lock(account, *b"smallock", 30).unwrap();
+    assert_eq!(test::recorded_events().count(), before + 2);
+    contract.set_lock(account, *b"smallock", 10).unwrap();
+    assert_eq!(test::recorded_events().count(), before + 3);
+    contract.remove_lock(account, *b"smallock").unwrap();
+    assert_eq!(test::recorded_events().count(), before + 4);
+    assert_eq!(contract.account(account).frozen, 50);
+}
@@ -0,0 +1,150 @@
+/// Test asset creation rules
+#[ink::test]
+fn create_asset_rules() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+
+    assert_eq!(
+        contract.create_asset(NATIVE_ASSET, 5, accounts.alice),
+        Err(Error::AssetExists)
+    );
+    assert_eq!(
+        contract.create_asset(1, 0, accounts.alice),
+        Err(Error::MinBalanceZero)
+    );
+    assert_eq!(contract.create_asset(1, 5, accounts.bob), Ok(()));
+    assert_eq!(
+        contract.create_asset(1, 5, accounts.bob),
+        Err(Error::AssetExists)
+    );
+
+    let details = contract.asset_details(1).unwrap();
+    assert_eq!(details.owner, accounts.bob);
+    assert_eq!(details.min_balance, 5);
+    assert_eq!(contract.asset_minimum_balance(1), 5);
+    assert_eq!(contract.asset_minimum_balance(NATIVE_ASSET), 10);
+    assert_eq!(contract.asset_details(2), None);
+
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+    assert_eq!(
+        contract.create_asset(2, 5, accounts.bob),
+        Err(Error::NotAllowed)
+    );
+}
+
+/// Test minting is gated by the asset owner and tracked per asset
+#[ink::test]
+fn asset_mint_per_asset_issuance() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+    contract.create_asset(1, 5, accounts.bob).unwrap();
+
+    // The contract owner does not own the new asset
+    assert_eq!(
+        contract.asset_mint(1, accounts.charlie, 50),
+        Err(Error::NotAllowed)
+    );
+    contract.mint(accounts.charlie, 100).unwrap();
+
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+    assert_eq!(
+        contract.asset_mint(1, accounts.charlie, 4),
+        Err(Error::ExistentialDeposit)
+    );
+    assert_eq!(contract.asset_mint(1, accounts.charlie, 50), Ok(()));
+    assert_eq!(contract.asset_mint(2, accounts.charlie, 50), Err(Error::UnknownAsset));
+
+    assert_eq!(contract.asset_balance(1, accounts.charlie), 50);
+    assert_eq!(contract.asset_total_issuance(1), 50);
+    assert_eq!(contract.balance(accounts.charlie), 100);
+    assert_eq!(contract.total_issuance(), 100);
+    assert_eq!(contract.asset_balance(NATIVE_ASSET, accounts.charlie), 100);
+}
+
+/// Test transfers use the minimum balance of the asset
+#[ink::test]
+fn asset_transfer_min_balance() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+    contract.create_asset(1, 5, accounts.alice).unwrap();
+    contract.asset_mint(1, accounts.bob, 100).unwrap();
+
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+    // Six units would be below the native existential deposit but are fine for the asset
+    assert_eq!(
+        contract.asset_transfer(1, accounts.charlie, 94, Preservation::Preserve),
+        Ok(())
+    );
+    assert_eq!(
+        contract.asset_transfer(1, accounts.charlie, 2, Preservation::Preserve),
+        Err(Error::Expendability)
+    );
+    assert_eq!(
+        contract.asset_transfer(1, accounts.charlie, 3, Preservation::Expendable),
+        Ok(())
+    );
+    // The remaining dust is removed from the asset issuance
+    assert_eq!(contract.asset_balance(1, accounts.bob), 0);
+    assert_eq!(contract.asset_balance(1, accounts.charlie), 97);
+    assert_eq!(contract.asset_total_issuance(1), 97);
+
+    assert_eq!(
+        contract.asset_transfer(2, accounts.charlie, 1, Preservation::Expendable),
+        Err(Error::UnknownAsset)
+    );
+    assert_eq!(
+        contract.asset_can_deposit(2, accounts.charlie, 1, Provenance::Minted),
+        DepositConsequence::UnknownAsset
+    );
+    assert_eq!(
+        contract.asset_can_withdraw(2, accounts.charlie, 1),
+        WithdrawConsequence::UnknownAsset
+    );
+}
+
+/// Test holds and freezes only apply to their own asset
+#[ink::test]
+fn asset_holds_and_freezes() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+    contract.create_asset(1, 5, accounts.alice).unwrap();
+
+    let account = accounts.bob;
+    contract.mint(account, 100).unwrap();
+    contract.asset_mint(1, account, 100).unwrap();
+
+    contract.asset_hold(1, *b"staking_", account, 30).unwrap();
+    contract.asset_set_freeze(1, *b"vesting_", account, 60).unwrap();
+
+    assert_eq!(contract.asset_balance_on_hold(1, *b"staking_", account), 30);
+    assert_eq!(contract.asset_total_balance_on_hold(1, account), 30);
+    assert_eq!(contract.asset_balance_frozen(1, *b"vesting_", account), 60);
+    assert_eq!(contract.balance_on_hold(*b"staking_", account), 0);
+    assert_eq!(contract.balance_frozen(*b"vesting_", account), 0);
+
+    // Held funds count towards the freeze, leaving 40 of the 70 free units spendable
+    assert_eq!(
+        contract.asset_reducible_balance(1, account, Preservation::Expendable, Fortitude::Polite),
+        40
+    );
+    assert_eq!(
+        contract.asset_can_withdraw(1, account, 41),
+        WithdrawConsequence::Frozen
+    );
+    assert_eq!(
+        contract.reducible_balance(account, Preservation::Expendable, Fortitude::Polite),
+        100
+    );
+
+    assert_eq!(
+        contract.asset_release(1, *b"staking_", account, 30, Precision::Exact),
+        Ok(30)
+    );
+    contract.asset_thaw(1, *b"vesting_", account).unwrap();
+    assert_eq!(contract.asset_account(1, account).frozen, 0);
+    assert_eq!(contract.asset_balance(1, account), 100);
+}
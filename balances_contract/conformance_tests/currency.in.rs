@@ -0,0 +1,168 @@
+/// Test deposit_creating only creates accounts at or above the existential deposit
+#[ink::test]
+fn deposit_creating_below_minimum() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+
+    assert_eq!(contract.deposit_creating(accounts.bob, 5), Ok(0));
+    assert_eq!(contract.balance(accounts.bob), 0);
+    assert_eq!(contract.total_issuance(), 0);
+
+    assert_eq!(contract.deposit_creating(accounts.bob, 50), Ok(50));
+    assert_eq!(contract.balance(accounts.bob), 50);
+    assert_eq!(contract.total_issuance(), 50);
+}
+
+/// Test deposit_into_existing fails on a dead account
+#[ink::test]
+fn deposit_into_existing_dead_account() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+
+    assert_eq!(
+        contract.deposit_into_existing(accounts.bob, 50),
+        Err(Error::DeadAccount)
+    );
+
+    contract.mint(accounts.bob, 50).unwrap();
+    assert_eq!(contract.deposit_into_existing(accounts.bob, 5), Ok(5));
+    assert_eq!(contract.balance(accounts.bob), 55);
+    assert_eq!(contract.total_issuance(), 55);
+
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+    assert_eq!(
+        contract.deposit_into_existing(accounts.bob, 5),
+        Err(Error::NotAllowed)
+    );
+}
+
+/// Test make_free_balance_be mints, burns and reaps
+#[ink::test]
+fn make_free_balance_be_success() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+
+    let account = accounts.bob;
+    // A new account is not created below the existential deposit
+    assert_eq!(contract.make_free_balance_be(account, 5), Ok(0));
+    assert_eq!(contract.total_issuance(), 0);
+
+    assert_eq!(contract.make_free_balance_be(account, 100), Ok(100));
+    assert_eq!(contract.total_issuance(), 100);
+
+    assert_eq!(contract.make_free_balance_be(account, 40), Ok(40));
+    assert_eq!(contract.total_issuance(), 40);
+
+    // Dropping below the existential deposit reaps the account
+    assert_eq!(contract.make_free_balance_be(account, 5), Ok(0));
+    assert_eq!(contract.balance(account), 0);
+    assert_eq!(contract.total_issuance(), 0);
+}
+
+/// Test currency_withdraw only respects locks for the given reasons
+#[ink::test]
+fn currency_withdraw_reasons() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+
+    let account = accounts.bob;
+    contract.mint(account, 100).unwrap();
+    contract
+        .set_lock_with_reasons(account, *b"feelock_", 80, WithdrawReasons::FEE)
+        .unwrap();
+
+    assert_eq!(
+        contract.ensure_can_withdraw(account, 50, WithdrawReasons::FEE, 50),
+        Err(Error::LiquidityRestrictions)
+    );
+    assert_eq!(
+        contract.ensure_can_withdraw(account, 50, WithdrawReasons::TRANSFER, 50),
+        Ok(())
+    );
+
+    assert_eq!(
+        contract.currency_withdraw(
+            account,
+            50,
+            WithdrawReasons::FEE,
+            ExistenceRequirement::AllowDeath
+        ),
+        Err(Error::LiquidityRestrictions)
+    );
+    assert_eq!(
+        contract.currency_withdraw(
+            account,
+            50,
+            WithdrawReasons::TRANSFER,
+            ExistenceRequirement::AllowDeath
+        ),
+        Ok(50)
+    );
+    assert_eq!(contract.balance(account), 50);
+    assert_eq!(contract.total_issuance(), 50);
+}
+
+/// Test currency_withdraw with KeepAlive refuses to kill the account
+#[ink::test]
+fn currency_withdraw_keep_alive() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+
+    let account = accounts.bob;
+    contract.mint(account, 100).unwrap();
+
+    assert_eq!(
+        contract.currency_withdraw(
+            account,
+            95,
+            WithdrawReasons::all(),
+            ExistenceRequirement::KeepAlive
+        ),
+        Err(Error::Expendability)
+    );
+    assert_eq!(
+        contract.currency_withdraw(
+            account,
+            101,
+            WithdrawReasons::all(),
+            ExistenceRequirement::AllowDeath
+        ),
+        Err(Error::InsufficientBalance)
+    );
+    assert_eq!(
+        contract.currency_withdraw(
+            account,
+            95,
+            WithdrawReasons::all(),
+            ExistenceRequirement::AllowDeath
+        ),
+        Ok(95)
+    );
+    assert_eq!(contract.balance(account), 0);
+}
+
+/// Test can_reserve agrees with reserve
+#[ink::test]
+fn can_reserve_with_lock() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+
+    let account = accounts.bob;
+    assert!(contract.can_reserve(account, 0));
+    assert!(!contract.can_reserve(account, 1));
+
+    contract.mint(account, 100).unwrap();
+    contract
+        .set_lock_with_reasons(account, *b"reslock_", 80, WithdrawReasons::RESERVE)
+        .unwrap();
+
+    // Reserved funds still count towards the lock, so it does not block reserving
+    assert!(contract.can_reserve(account, 100));
+    assert_eq!(contract.reserve(account, 100), Ok(()));
+}
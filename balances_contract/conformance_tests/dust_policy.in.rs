@@ -0,0 +1,164 @@
+/// Helper to leave 5 units of dust on bob by transferring the rest of 100 to charlie
+fn leave_dust(contract: &mut BalancesContract) {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    contract.mint(accounts.bob, 100).unwrap();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+    contract.transfer(accounts.charlie, 95).unwrap();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+}
+
+/// Test the trap policy moves dust to the trap and emits an event
+#[ink::test]
+fn dust_policy_trap() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract =
+        BalancesContract::new_with_dust_policy(10, 5, DustPolicy::Trap(accounts.django));
+    assert_eq!(contract.dust_trap(), Some(accounts.django));
+
+    let events_before = test::recorded_events().count();
+    leave_dust(&mut contract);
+    // Endowed for the mint, then for the transfer Endowed for the recipient, Killed for
+    // the sender, Endowed and DustTrapped for the trap and Transfer
+    assert_eq!(test::recorded_events().count(), events_before + 6);
+    assert_eq!(contract.balance(accounts.bob), 0);
+    assert_eq!(contract.balance(accounts.django), 5);
+    assert_eq!(contract.total_issuance(), 100);
+}
+
+/// Test the treasury policy places dust on hold on the treasury
+#[ink::test]
+fn dust_policy_treasury() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+    contract
+        .set_dust_policy(DustPolicy::Treasury(accounts.eve))
+        .unwrap();
+    assert_eq!(contract.dust_trap(), None);
+
+    leave_dust(&mut contract);
+    assert_eq!(contract.balance(accounts.bob), 0);
+    assert_eq!(contract.balance(accounts.eve), 0);
+    assert_eq!(contract.balance_on_hold(DUST_HOLD_REASON, accounts.eve), 5);
+    assert_eq!(contract.total_issuance(), 100);
+}
+
+/// Test the pot policy accumulates dust that can be swept by a dust manager
+#[ink::test]
+fn dust_policy_pot_and_sweep() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = BalancesContract::new_with_dust_policy(10, 5, DustPolicy::Pot);
+    assert_eq!(contract.dust_policy(), DustPolicy::Pot);
+
+    leave_dust(&mut contract);
+    assert_eq!(contract.dust_pot(NATIVE_ASSET), 5);
+    assert_eq!(contract.total_issuance(), 100);
+
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+    assert_eq!(
+        contract.sweep_dust(NATIVE_ASSET, accounts.bob),
+        Err(Error::NotAllowed)
+    );
+
+    // The pot cannot create an account below the existential deposit
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    assert_eq!(
+        contract.sweep_dust(NATIVE_ASSET, accounts.django),
+        Err(Error::ExistentialDeposit)
+    );
+    assert_eq!(contract.sweep_dust(NATIVE_ASSET, accounts.charlie), Ok(5));
+    assert_eq!(contract.dust_pot(NATIVE_ASSET), 0);
+    assert_eq!(contract.balance(accounts.charlie), 100);
+    assert_eq!(contract.total_issuance(), 100);
+}
+
+/// Test callers can route dust themselves through handle_raw_dust and handle_dust
+#[ink::test]
+fn handle_raw_dust_and_handle_dust() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+    contract.mint(accounts.bob, 100).unwrap();
+    contract.mint(accounts.charlie, 100).unwrap();
+
+    // write_balance returns the dust, which is still part of total issuance
+    assert_eq!(contract.write_balance(accounts.bob, 5), Ok(Some(5)));
+    assert_eq!(contract.total_issuance(), 105);
+    let events_before = test::recorded_events().count();
+    contract.handle_raw_dust(5).unwrap();
+    assert_eq!(test::recorded_events().count(), events_before + 1);
+    assert_eq!(contract.total_issuance(), 100);
+
+    assert_eq!(contract.set_balance(accounts.charlie, 5), Ok(5));
+    assert_eq!(contract.handle_dust(accounts.charlie), Ok(5));
+    assert_eq!(contract.balance(accounts.charlie), 0);
+    assert_eq!(contract.total_issuance(), 0);
+
+    contract.mint(accounts.django, 50).unwrap();
+    assert_eq!(contract.handle_dust(accounts.django), Ok(0));
+    assert_eq!(contract.balance(accounts.django), 50);
+
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+    assert_eq!(contract.handle_raw_dust(5), Err(Error::NotAllowed));
+}
+
+/// Test dust routed to the recipient of the transfer that created it is not lost
+#[ink::test]
+fn dust_routed_to_transfer_recipient() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract =
+        BalancesContract::new_with_dust_policy(10, 5, DustPolicy::Treasury(accounts.charlie));
+
+    leave_dust(&mut contract);
+    assert_eq!(contract.balance(accounts.bob), 0);
+    assert_eq!(contract.balance(accounts.charlie), 95);
+    assert_eq!(
+        contract.balance_on_hold(DUST_HOLD_REASON, accounts.charlie),
+        5
+    );
+    assert_eq!(contract.total_issuance(), 100);
+
+    contract
+        .set_dust_policy(DustPolicy::Trap(accounts.charlie))
+        .unwrap();
+    leave_dust(&mut contract);
+    assert_eq!(contract.balance(accounts.bob), 0);
+    assert_eq!(contract.balance(accounts.charlie), 195);
+    assert_eq!(contract.total_balance(accounts.charlie), 200);
+    assert_eq!(contract.total_issuance(), 200);
+}
+
+/// Test only dust pending from write_balance is routed, so the funds in accounts never
+/// exceed total issuance however often raw dust is handled
+#[ink::test]
+fn handle_raw_dust_only_routes_pending_dust() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract =
+        BalancesContract::new_with_dust_policy(10, 5, DustPolicy::Trap(accounts.django));
+    contract.mint(accounts.bob, 100).unwrap();
+    contract.mint(accounts.charlie, 100).unwrap();
+
+    assert_eq!(contract.write_balance(accounts.bob, 9), Ok(Some(9)));
+    assert_eq!(contract.raw_dust(accounts.alice), 9);
+    assert_eq!(contract.total_issuance(), 109);
+    for _ in 0..3 {
+        contract.handle_raw_dust(1_000).unwrap();
+        assert!(
+            contract.total_balance(accounts.bob)
+                + contract.total_balance(accounts.charlie)
+                + contract.total_balance(accounts.django)
+                <= contract.total_issuance()
+        );
+    }
+    assert_eq!(contract.balance(accounts.django), 9);
+    assert_eq!(contract.raw_dust(accounts.alice), 0);
+
+    // Under the burn policy repeated calls cannot burn issuance nobody gave up
+    contract.set_dust_policy(DustPolicy::Burn).unwrap();
+    contract.handle_raw_dust(1_000).unwrap();
+    assert_eq!(contract.total_issuance(), 109);
+}
@@ -0,0 +1,328 @@
+/// Test that a credit not issued by the contract cannot be resolved.
+#[ink::test]
+fn resolve_forged_credit() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+
+    let account = accounts.bob;
+    let forged = CreditImbalance { id: 42, amount: 1_000 };
+
+    assert_eq!(
+        contract.resolve_credit(account, forged),
+        Err(Error::InvalidImbalance)
+    );
+    assert_eq!(contract.balance(account), 0);
+    assert_eq!(contract.total_issuance(), 0);
+}
+
+/// Test that a credit handle with a tampered amount is rejected.
+#[ink::test]
+fn resolve_tampered_credit() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+
+    let account = accounts.bob;
+    let (credit, _debt) = contract.pair(50).unwrap();
+    let tampered = CreditImbalance {
+        id: credit.id(),
+        amount: credit.peek() * 10,
+    };
+
+    assert_eq!(
+        contract.resolve_credit(account, tampered),
+        Err(Error::InvalidImbalance)
+    );
+    assert_eq!(contract.balance(account), 0);
+
+    // The genuine handle is still valid
+    contract.resolve_credit(account, credit).unwrap();
+    assert_eq!(contract.balance(account), 50);
+}
+
+/// Test that a credit can only be resolved once.
+#[ink::test]
+fn resolve_credit_twice() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+
+    let account = accounts.bob;
+    let credit = contract.issue(50).unwrap();
+
+    contract.resolve_credit(account, credit).unwrap();
+    assert_eq!(
+        contract.resolve_credit(account, credit),
+        Err(Error::InvalidImbalance)
+    );
+    assert_eq!(contract.balance(account), 50);
+    assert_eq!(contract.total_issuance(), 50);
+}
+
+/// Test that a failed resolution leaves the credit outstanding.
+#[ink::test]
+fn resolve_credit_failure_keeps_credit() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+
+    let (credit, _debt) = contract.pair(5).unwrap();
+
+    // Below the minimum balance for a new account
+    assert_eq!(
+        contract.resolve_credit(accounts.bob, credit),
+        Err(Error::ExistentialDeposit)
+    );
+
+    // The credit can still be resolved into an existing account
+    contract.mint(accounts.charlie, 100).unwrap();
+    contract.resolve_credit(accounts.charlie, credit).unwrap();
+    assert_eq!(contract.balance(accounts.charlie), 105);
+}
+
+/// Test that imbalances cannot be used by a caller they were not issued to.
+#[ink::test]
+fn settle_debt_other_holder() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+
+    let account = accounts.bob;
+    contract.mint(account, 100).unwrap();
+    let (_credit, debt) = contract.pair(30).unwrap();
+
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+    assert_eq!(
+        contract.settle_debt(account, debt, Preservation::Expendable),
+        Err(Error::InvalidImbalance)
+    );
+    assert_eq!(contract.drop_debt(debt), Err(Error::InvalidImbalance));
+    assert_eq!(contract.balance(account), 100);
+
+    // The issuing caller can settle it
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let remaining = contract
+        .settle_debt(account, debt, Preservation::Expendable)
+        .unwrap();
+    assert_eq!(remaining.peek(), 0);
+    assert_eq!(contract.balance(account), 70);
+}
+
+/// Test that the unsettled part of a debt remains a tracked debt.
+#[ink::test]
+fn settle_debt_remaining_is_tracked() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+
+    let account = accounts.bob;
+    let other = accounts.charlie;
+    contract.mint(account, 30).unwrap();
+    contract.mint(other, 100).unwrap();
+    let (_credit, debt) = contract.pair(100).unwrap();
+
+    let remaining = contract
+        .settle_debt(account, debt, Preservation::Expendable)
+        .unwrap();
+    assert_eq!(remaining.peek(), 70);
+
+    // The original debt is consumed
+    assert_eq!(
+        contract.settle_debt(other, debt, Preservation::Expendable),
+        Err(Error::InvalidImbalance)
+    );
+
+    // The remaining debt can be settled against another account
+    let remaining = contract
+        .settle_debt(other, remaining, Preservation::Expendable)
+        .unwrap();
+    assert_eq!(remaining.peek(), 0);
+    assert_eq!(contract.balance(other), 30);
+}
+
+/// Test dropping credits and debts.
+#[ink::test]
+fn drop_imbalances() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+
+    let (credit, debt) = contract.pair(50).unwrap();
+
+    contract.drop_credit(credit).unwrap();
+    contract.drop_debt(debt).unwrap();
+
+    // Dropped imbalances cannot be used or dropped again
+    assert_eq!(contract.drop_credit(credit), Err(Error::InvalidImbalance));
+    assert_eq!(contract.drop_debt(debt), Err(Error::InvalidImbalance));
+    assert_eq!(
+        contract.resolve_credit(accounts.bob, credit),
+        Err(Error::InvalidImbalance)
+    );
+
+    // Zero imbalances need no record
+    assert_eq!(contract.drop_credit(CreditImbalance::zero()), Ok(()));
+    assert_eq!(contract.drop_debt(DebtImbalance::zero()), Ok(()));
+}
+
+/// Test that only the owner can create imbalance pairs.
+#[ink::test]
+fn pair_not_owner() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+    assert_eq!(contract.pair(50), Err(Error::NotAllowed));
+}
+
+/// Test splitting credits and debts.
+#[ink::test]
+fn split_imbalances() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+
+    let (credit, debt) = contract.pair(100).unwrap();
+
+    let (first, second) = contract.split_credit(credit, 30).unwrap();
+    assert_eq!(first.peek(), 30);
+    assert_eq!(second.peek(), 70);
+
+    // The split credit is consumed, the parts are independently usable
+    assert_eq!(contract.drop_credit(credit), Err(Error::InvalidImbalance));
+    contract.resolve_credit(accounts.bob, first).unwrap();
+    contract.resolve_credit(accounts.charlie, second).unwrap();
+    assert_eq!(contract.balance(accounts.bob), 30);
+    assert_eq!(contract.balance(accounts.charlie), 70);
+
+    // Splitting more than the debt leaves an empty second part
+    let (first, second) = contract.split_debt(debt, 150).unwrap();
+    assert_eq!(first.peek(), 100);
+    assert_eq!(second, DebtImbalance::zero());
+    assert_eq!(contract.drop_debt(debt), Err(Error::InvalidImbalance));
+    contract.drop_debt(first).unwrap();
+}
+
+/// Test merging and subsuming credits and debts.
+#[ink::test]
+fn merge_and_subsume_imbalances() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+
+    let (credit_a, debt_a) = contract.pair(40).unwrap();
+    let (credit_b, debt_b) = contract.pair(60).unwrap();
+
+    let merged = contract.merge_credits(credit_a, credit_b).unwrap();
+    assert_eq!(merged.peek(), 100);
+    assert_eq!(contract.drop_credit(credit_a), Err(Error::InvalidImbalance));
+    assert_eq!(contract.drop_credit(credit_b), Err(Error::InvalidImbalance));
+
+    // Subsuming keeps the identifier of the target
+    let subsumed = contract.subsume_debt(debt_a, debt_b).unwrap();
+    assert_eq!(subsumed.id(), debt_a.id());
+    assert_eq!(subsumed.peek(), 100);
+    assert_eq!(contract.drop_debt(debt_a), Err(Error::InvalidImbalance));
+    assert_eq!(contract.drop_debt(debt_b), Err(Error::InvalidImbalance));
+
+    // Subsuming into a zero imbalance records a new one
+    let subsumed_credit = contract
+        .subsume_credit(CreditImbalance::zero(), merged)
+        .unwrap();
+    assert_eq!(subsumed_credit.peek(), 100);
+    let merged_debt = contract
+        .merge_debts(subsumed, DebtImbalance::zero())
+        .unwrap();
+    assert_eq!(merged_debt.peek(), 100);
+
+    contract.drop_credit(subsumed_credit).unwrap();
+    contract.drop_debt(merged_debt).unwrap();
+}
+
+/// Test that an imbalance cannot be merged with itself.
+#[ink::test]
+fn merge_imbalance_with_itself() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+
+    let (credit, debt) = contract.pair(50).unwrap();
+
+    assert_eq!(
+        contract.merge_credits(credit, credit),
+        Err(Error::InvalidImbalance)
+    );
+    assert_eq!(
+        contract.subsume_credit(credit, credit),
+        Err(Error::InvalidImbalance)
+    );
+    assert_eq!(
+        contract.merge_debts(debt, debt),
+        Err(Error::InvalidImbalance)
+    );
+    assert_eq!(
+        contract.subsume_debt(debt, debt),
+        Err(Error::InvalidImbalance)
+    );
+
+    // The imbalances are untouched
+    contract.drop_credit(credit).unwrap();
+    contract.drop_debt(debt).unwrap();
+}
+
+/// Test that merging credits beyond the maximum balance fails.
+#[ink::test]
+fn merge_credits_overflow() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+
+    let (credit_a, _debt_a) = contract.pair(Balance::MAX).unwrap();
+    let (credit_b, _debt_b) = contract.pair(1).unwrap();
+
+    assert_eq!(
+        contract.merge_credits(credit_a, credit_b),
+        Err(Error::Overflow)
+    );
+    contract.drop_credit(credit_a).unwrap();
+    contract.drop_credit(credit_b).unwrap();
+}
+
+/// Test offsetting credits against debts.
+#[ink::test]
+fn offset_imbalances() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+
+    contract.mint(accounts.bob, 100).unwrap();
+    let credit = contract.issue(70).unwrap();
+    let debt = contract.rescind(50).unwrap();
+    assert_eq!(contract.total_issuance(), 120);
+
+    // The larger credit leaves a credit behind
+    let credit = match contract.offset(credit, debt).unwrap() {
+        SameOrOther::Same(credit) => credit,
+        other => panic!("unexpected offset result: {:?}", other),
+    };
+    assert_eq!(credit.peek(), 20);
+    assert_eq!(contract.drop_debt(debt), Err(Error::InvalidImbalance));
+
+    // The larger debt leaves a debt behind
+    let debt = contract.rescind(30).unwrap();
+    let debt = match contract.offset(credit, debt).unwrap() {
+        SameOrOther::Other(debt) => debt,
+        other => panic!("unexpected offset result: {:?}", other),
+    };
+    assert_eq!(debt.peek(), 10);
+
+    // Equal amounts cancel out
+    let credit = contract.issue(10).unwrap();
+    assert_eq!(contract.offset(credit, debt), Ok(SameOrOther::None));
+
+    // Offsetting never changes total issuance
+    assert_eq!(contract.total_issuance(), 100);
+    assert_eq!(contract.balance(accounts.bob), 100);
+}
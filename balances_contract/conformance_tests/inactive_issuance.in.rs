@@ -0,0 +1,61 @@
+/// Test active issuance stays total minus inactive issuance across mints and burns
+#[ink::test]
+fn inactive_issuance_is_conserved() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+
+    contract.mint(accounts.bob, 100).unwrap();
+    contract.deactivate(30).unwrap();
+    assert_eq!(contract.inactive_issuance(), 30);
+    assert_eq!(contract.active_issuance(), 70);
+
+    contract.mint(accounts.bob, 50).unwrap();
+    assert_eq!(contract.inactive_issuance(), 30);
+    assert_eq!(contract.active_issuance(), 120);
+
+    contract
+        .burn_from(
+            accounts.bob,
+            100,
+            Preservation::Expendable,
+            Precision::Exact,
+            Fortitude::Polite,
+        )
+        .unwrap();
+    assert_eq!(contract.total_issuance(), 50);
+    assert_eq!(contract.active_issuance(), 20);
+
+    // Burning below the inactive issuance reactivates the excess
+    let events_before = test::recorded_events().count();
+    contract.shelve(accounts.bob, 30).unwrap();
+    assert_eq!(contract.total_issuance(), 20);
+    assert_eq!(contract.inactive_issuance(), 20);
+    assert_eq!(contract.active_issuance(), 0);
+    assert_eq!(test::recorded_events().count(), events_before + 2);
+
+    contract.set_total_issuance(10).unwrap();
+    assert_eq!(contract.inactive_issuance(), 10);
+    assert_eq!(contract.active_issuance(), 0);
+}
+
+/// Test deactivate and reactivate are bounded and emit events for the actual change
+#[ink::test]
+fn deactivate_and_reactivate_events() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+    contract.mint(accounts.bob, 100).unwrap();
+    let events_before = test::recorded_events().count();
+
+    contract.deactivate(0).unwrap();
+    contract.reactivate(10).unwrap();
+    assert_eq!(test::recorded_events().count(), events_before);
+
+    contract.deactivate(150).unwrap();
+    assert_eq!(contract.inactive_issuance(), 100);
+    contract.reactivate(40).unwrap();
+    assert_eq!(contract.inactive_issuance(), 60);
+    assert_eq!(contract.active_issuance(), 40);
+    assert_eq!(test::recorded_events().count(), events_before + 2);
+}
@@ -317,8 +317,8 @@ fn transfer_success() {
     let account_0 = accounts.bob;
     let account_1 = accounts.charlie;
     let initial_balance = contract.minimum_balance() + 10;
-    contract.set_balance(account_0, initial_balance);
-    contract.set_balance(account_1, initial_balance);
+    contract.set_balance(account_0, initial_balance).unwrap();
+    contract.set_balance(account_1, initial_balance).unwrap();
 
     // Test: Transfer an amount from account_0 to account_1
     test::set_caller::<ink::env::DefaultEnvironment>(account_0);
@@ -347,8 +347,8 @@ fn transfer_expendable_all() {
     let account_0 = accounts.bob;
     let account_1 = accounts.charlie;
     let initial_balance = contract.minimum_balance() + 10;
-    contract.set_balance(account_0, initial_balance);
-    contract.set_balance(account_1, initial_balance);
+    contract.set_balance(account_0, initial_balance).unwrap();
+    contract.set_balance(account_1, initial_balance).unwrap();
 
     // Test: Transfer entire balance from account_0 to account_1
     test::set_caller::<ink::env::DefaultEnvironment>(account_0);
@@ -382,8 +382,8 @@ fn transfer_expendable_dust_no_trap() {
     let account_1 = accounts.charlie;
     let initial_balance = contract.existential_deposit() + 10;
     
-    contract.set_balance(account_0, initial_balance);
-    contract.set_balance(account_1, initial_balance);
+    contract.set_balance(account_0, initial_balance).unwrap();
+    contract.set_balance(account_1, initial_balance).unwrap();
 
     let initial_total_issuance = contract.total_issuance();
 
@@ -426,8 +426,8 @@ fn transfer_expendable_dust_with_trap() {
     let account_1 = accounts.charlie;
     let initial_balance = contract.existential_deposit() + 10;
     
-    contract.set_balance(account_0, initial_balance);
-    contract.set_balance(account_1, initial_balance);
+    contract.set_balance(account_0, initial_balance).unwrap();
+    contract.set_balance(account_1, initial_balance).unwrap();
 
     let initial_total_issuance = contract.total_issuance();
     let initial_dust_trap_balance = contract.total_balance(dust_trap);
@@ -468,8 +468,8 @@ fn transfer_protect_preserve() {
     let account_0 = accounts.bob;
     let account_1 = accounts.charlie;
     let initial_balance = contract.existential_deposit() + 10;
-    contract.set_balance(account_0, initial_balance);
-    contract.set_balance(account_1, initial_balance);
+    contract.set_balance(account_0, initial_balance).unwrap();
+    contract.set_balance(account_1, initial_balance).unwrap();
 
     // Verify: Transfer keep_alive entire balance from account_0 to account_1 should Err
     test::set_caller::<ink::env::DefaultEnvironment>(account_0);
@@ -516,7 +516,7 @@ fn set_balance_mint_success() {
 
     // Test: Increase the account balance with set_balance
     let increase_amount: Balance = 5;
-    let new = contract.set_balance(account, initial_balance + increase_amount);
+    let new = contract.set_balance(account, initial_balance + increase_amount).unwrap();
 
     // Verify: set_balance returned the new balance
     let expected_new = initial_balance + increase_amount;
@@ -543,7 +543,7 @@ fn set_balance_burn_success() {
 
     // Test: Decrease the account balance with set_balance
     let burn_amount: Balance = 5;
-    let new = contract.set_balance(account, initial_balance - burn_amount);
+    let new = contract.set_balance(account, initial_balance - burn_amount).unwrap();
 
     // Verify: set_balance returned the new balance
     let expected_new = initial_balance - burn_amount;
@@ -613,6 +613,99 @@ fn can_deposit_overflow() {
     assert_eq!(ret, DepositConsequence::Overflow);
 }
 
+/// Test that only minted deposits are checked against total issuance.
+#[ink::test]
+fn can_deposit_provenance() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+
+    let account = accounts.bob;
+    contract.mint(account, 100).unwrap();
+    contract.set_total_issuance(Balance::MAX - 5).unwrap();
+
+    assert_eq!(
+        contract.can_deposit(account, 10, Provenance::Minted),
+        DepositConsequence::Overflow
+    );
+    assert_eq!(
+        contract.can_deposit(account, 10, Provenance::Extant),
+        DepositConsequence::Success
+    );
+
+    // Minting agrees with the query
+    assert_eq!(contract.mint(account, 10), Err(Error::Overflow));
+    assert_eq!(
+        contract.increase_balance(account, 10, Precision::BestEffort),
+        Ok(5)
+    );
+}
+
+/// Test the can_deposit function for returning a cannot create error.
+#[ink::test]
+fn can_deposit_cannot_create() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+
+    let account = accounts.bob;
+    let sender = accounts.charlie;
+    contract.mint(sender, 100).unwrap();
+    contract.set_blocked(account, true).unwrap();
+    assert!(contract.is_blocked(account));
+
+    // Test: Blocked accounts cannot be created
+    assert_eq!(
+        contract.can_deposit(account, 50, Provenance::Minted),
+        DepositConsequence::CannotCreate
+    );
+    assert_eq!(
+        contract.can_deposit(account, 50, Provenance::Extant),
+        DepositConsequence::CannotCreate
+    );
+
+    // Verify: Every deposit path agrees with the query
+    assert_eq!(contract.mint(account, 50), Err(Error::CannotCreate));
+    assert_eq!(
+        contract.increase_balance(account, 50, Precision::BestEffort),
+        Err(Error::CannotCreate)
+    );
+    test::set_caller::<ink::env::DefaultEnvironment>(sender);
+    assert_eq!(contract.transfer(account, 50), Err(Error::CannotCreate));
+    assert_eq!(contract.balance(sender), 100);
+
+    // Verify: An account that already exists keeps receiving funds
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    contract.set_blocked(account, false).unwrap();
+    contract.mint(account, 50).unwrap();
+    contract.set_blocked(account, true).unwrap();
+    assert_eq!(
+        contract.can_deposit(account, 5, Provenance::Minted),
+        DepositConsequence::Success
+    );
+    test::set_caller::<ink::env::DefaultEnvironment>(sender);
+    assert_eq!(contract.transfer(account, 5), Ok(()));
+    assert_eq!(contract.balance(account), 55);
+}
+
+/// Test that transfers cannot create an account below the minimum balance.
+#[ink::test]
+fn transfer_below_minimum_to_new_account() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+
+    let sender = accounts.bob;
+    contract.mint(sender, 100).unwrap();
+
+    test::set_caller::<ink::env::DefaultEnvironment>(sender);
+    assert_eq!(
+        contract.transfer(accounts.charlie, 5),
+        Err(Error::ExistentialDeposit)
+    );
+    assert_eq!(contract.balance(sender), 100);
+}
+
 /// Test the can_withdraw function for returning a success value.
 #[ink::test]
 fn can_withdraw_success() {
@@ -651,6 +744,34 @@ fn can_withdraw_reduced_to_zero() {
     assert_eq!(ret, WithdrawConsequence::ReducedToZero(contract.minimum_balance() - 1));
 }
 
+/// Test withdrawing the whole balance reduces the account to zero and still succeeds.
+#[ink::test]
+fn can_withdraw_whole_balance_reduced_to_zero() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+
+    let account = accounts.bob;
+    contract.mint(account, 100).unwrap();
+
+    // Verify: A withdrawal leaving exactly zero is ReducedToZero(0), not Success
+    assert_eq!(
+        contract.can_withdraw(account, 100),
+        WithdrawConsequence::ReducedToZero(0)
+    );
+    assert_eq!(
+        contract.burn_from(
+            account,
+            100,
+            Preservation::Expendable,
+            Precision::Exact,
+            Fortitude::Polite,
+        ),
+        Ok(100)
+    );
+    assert_eq!(contract.balance(account), 0);
+}
+
 /// Test the can_withdraw function for returning a low balance error.
 #[ink::test]
 fn can_withdraw_balance_low() {
@@ -705,4 +826,76 @@ fn reducible_balance_protect_preserve() {
     assert_eq!(ret, initial_balance - contract.minimum_balance());
     let ret = contract.reducible_balance(account, Preservation::Preserve, Fortitude::Polite);
     assert_eq!(ret, initial_balance - contract.minimum_balance());
-}
\ No newline at end of file
+}
+/// Test the can_withdraw function for returning a frozen error.
+#[ink::test]
+fn can_withdraw_frozen() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+
+    let account = accounts.bob;
+    contract.mint(account, 100).unwrap();
+    contract.set_lock(account, *b"testlock", 50).unwrap();
+
+    // Test: Withdraw into the frozen balance
+    let ret = contract.can_withdraw(account, 60);
+
+    // Verify: Returns Frozen, and the mutations agree with it
+    assert_eq!(ret, WithdrawConsequence::Frozen);
+    assert_eq!(
+        contract.burn_from(
+            account,
+            60,
+            Preservation::Expendable,
+            Precision::Exact,
+            Fortitude::Polite,
+        ),
+        Err(Error::LiquidityRestrictions)
+    );
+    test::set_caller::<ink::env::DefaultEnvironment>(account);
+    assert_eq!(
+        contract.transfer(accounts.charlie, 60),
+        Err(Error::LiquidityRestrictions)
+    );
+
+    // Verify: Withdrawing more than the total issuance is an underflow, but the transfer
+    // still fails for the missing balance
+    assert_eq!(
+        contract.can_withdraw(account, 101),
+        WithdrawConsequence::Underflow
+    );
+    assert_eq!(
+        contract.transfer(accounts.charlie, 101),
+        Err(Error::InsufficientBalance)
+    );
+}
+
+/// Test the can_withdraw function for returning an underflow error.
+#[ink::test]
+fn can_withdraw_underflow() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+
+    let account = accounts.bob;
+    contract.mint(account, 100).unwrap();
+    contract.set_total_issuance(50).unwrap();
+
+    // Test: Withdraw more than the total issuance
+    let ret = contract.can_withdraw(account, 60);
+
+    // Verify: Returns Underflow, and burning agrees with it
+    assert_eq!(ret, WithdrawConsequence::Underflow);
+    assert_eq!(
+        contract.burn_from(
+            account,
+            60,
+            Preservation::Expendable,
+            Precision::Exact,
+            Fortitude::Polite,
+        ),
+        Err(Error::Underflow)
+    );
+    assert_eq!(contract.balance(account), 100);
+}
@@ -0,0 +1,282 @@
+/// Test the `set_freeze` function for freezing funds for a reason.
+#[ink::test]
+fn set_freeze_success() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+
+    let account = accounts.bob;
+    let reason = *b"staking_";
+    contract.mint(account, 100).unwrap();
+
+    contract.set_freeze(reason, account, 40).unwrap();
+    assert_eq!(contract.balance_frozen(reason, account), 40);
+    assert_eq!(contract.account(account).frozen, 40);
+    assert_eq!(contract.usable_balance(account), 60);
+
+    // set_freeze overwrites the previous amount, even when smaller
+    contract.set_freeze(reason, account, 25).unwrap();
+    assert_eq!(contract.balance_frozen(reason, account), 25);
+    assert_eq!(contract.account(account).frozen, 25);
+
+    // Setting a zero amount removes the freeze
+    contract.set_freeze(reason, account, 0).unwrap();
+    assert_eq!(contract.balance_frozen(reason, account), 0);
+    assert_eq!(contract.account(account).frozen, 0);
+}
+
+/// Test that `extend_freeze` never reduces an existing freeze.
+#[ink::test]
+fn extend_freeze_takes_max() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+
+    let account = accounts.bob;
+    let reason = *b"staking_";
+    contract.mint(account, 100).unwrap();
+
+    contract.extend_freeze(reason, account, 30).unwrap();
+    assert_eq!(contract.balance_frozen(reason, account), 30);
+
+    contract.extend_freeze(reason, account, 20).unwrap();
+    assert_eq!(contract.balance_frozen(reason, account), 30);
+
+    contract.extend_freeze(reason, account, 50).unwrap();
+    assert_eq!(contract.balance_frozen(reason, account), 50);
+    assert_eq!(contract.account(account).frozen, 50);
+}
+
+/// Test `increase_frozen` and `decrease_frozen`.
+#[ink::test]
+fn increase_decrease_frozen() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+
+    let account = accounts.bob;
+    let reason = *b"staking_";
+    contract.mint(account, 100).unwrap();
+
+    contract.increase_frozen(reason, account, 30).unwrap();
+    contract.increase_frozen(reason, account, 15).unwrap();
+    assert_eq!(contract.balance_frozen(reason, account), 45);
+
+    contract.decrease_frozen(reason, account, 20).unwrap();
+    assert_eq!(contract.balance_frozen(reason, account), 25);
+
+    // Decreasing below zero removes the freeze
+    contract.decrease_frozen(reason, account, 100).unwrap();
+    assert_eq!(contract.balance_frozen(reason, account), 0);
+    assert_eq!(contract.account(account).frozen, 0);
+}
+
+/// Test that the frozen balance is the maximum over both locks and freezes.
+#[ink::test]
+fn frozen_is_max_of_locks_and_freezes() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+
+    let account = accounts.bob;
+    let reason = *b"staking_";
+    let lock_id = *b"testlock";
+    contract.mint(account, 100).unwrap();
+
+    contract.set_lock(account, lock_id, 30).unwrap();
+    contract.set_freeze(reason, account, 50).unwrap();
+    assert_eq!(contract.account(account).frozen, 50);
+
+    // Removing the lock leaves the freeze in place
+    contract.remove_lock(account, lock_id).unwrap();
+    assert_eq!(contract.account(account).frozen, 50);
+
+    // Locks and freezes are stored separately
+    contract.set_lock(account, lock_id, 70).unwrap();
+    assert_eq!(contract.account(account).frozen, 70);
+    contract.thaw(reason, account).unwrap();
+    assert_eq!(contract.balance_frozen(reason, account), 0);
+    assert_eq!(contract.account(account).frozen, 70);
+}
+
+/// Test that frozen funds cannot be transferred.
+#[ink::test]
+fn transfer_with_freeze() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+
+    let from = accounts.bob;
+    let to = accounts.charlie;
+    contract.mint(from, 100).unwrap();
+    contract.set_freeze(*b"staking_", from, 50).unwrap();
+
+    test::set_caller::<ink::env::DefaultEnvironment>(from);
+    assert_eq!(contract.transfer(to, 51), Err(Error::LiquidityRestrictions));
+    assert_eq!(contract.transfer(to, 50), Ok(()));
+    assert_eq!(contract.balance(from), 50);
+}
+
+/// Test that the number of distinct freezes is bounded.
+#[ink::test]
+fn set_freeze_too_many() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 2, None);
+
+    let account = accounts.bob;
+    contract.mint(account, 100).unwrap();
+
+    contract.set_freeze(*b"freeze01", account, 10).unwrap();
+    contract.set_freeze(*b"freeze02", account, 10).unwrap();
+
+    assert!(!contract.can_freeze(*b"freeze03", account));
+    assert_eq!(
+        contract.set_freeze(*b"freeze03", account, 10),
+        Err(Error::TooManyFreezes)
+    );
+    assert_eq!(
+        contract.extend_freeze(*b"freeze03", account, 10),
+        Err(Error::TooManyFreezes)
+    );
+
+    // Existing freezes can still be updated
+    assert!(contract.can_freeze(*b"freeze01", account));
+    contract.set_freeze(*b"freeze01", account, 20).unwrap();
+    assert_eq!(contract.account(account).frozen, 20);
+}
+
+/// Test that `Fortitude::Force` ignores freezes but never holds.
+#[ink::test]
+fn force_ignores_freezes_not_holds() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+
+    let account = accounts.bob;
+    contract.mint(account, 100).unwrap();
+    contract.hold(*b"staking_", account, 30).unwrap();
+    contract.set_freeze(*b"staking_", account, 50).unwrap();
+
+    // The hold covers part of the freeze
+    assert_eq!(
+        contract.reducible_balance(account, Preservation::Expendable, Fortitude::Polite),
+        50
+    );
+    assert_eq!(
+        contract.reducible_balance(account, Preservation::Expendable, Fortitude::Force),
+        60
+    );
+    assert_eq!(
+        contract.reducible_balance(account, Preservation::Preserve, Fortitude::Force),
+        60
+    );
+    assert_eq!(
+        contract.can_withdraw(account, 51),
+        WithdrawConsequence::Frozen
+    );
+
+    // The held funds are never reached, even when forced
+    assert_eq!(
+        contract.burn_from(
+            account,
+            80,
+            Preservation::Expendable,
+            Precision::Exact,
+            Fortitude::Force,
+        ),
+        Err(Error::InsufficientBalance)
+    );
+    let burned = contract
+        .burn_from(
+            account,
+            80,
+            Preservation::Expendable,
+            Precision::BestEffort,
+            Fortitude::Force,
+        )
+        .unwrap();
+    assert_eq!(burned, 60);
+    assert_eq!(contract.balance(account), 10);
+    assert_eq!(contract.balance_on_hold(*b"staking_", account), 30);
+}
+
+/// Test that holds count towards satisfying freezes.
+#[ink::test]
+fn holds_overlap_freezes() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+
+    let account = accounts.bob;
+    contract.mint(account, 100).unwrap();
+    contract.set_freeze(*b"staking_", account, 50).unwrap();
+    contract.hold(*b"staking_", account, 20).unwrap();
+
+    // Only the part of the freeze not covered by the hold is untouchable
+    assert_eq!(contract.usable_balance(account), 50);
+    assert_eq!(
+        contract.reducible_balance(account, Preservation::Preserve, Fortitude::Polite),
+        50
+    );
+    assert_eq!(contract.can_withdraw(account, 50), WithdrawConsequence::Success);
+    assert_eq!(contract.can_withdraw(account, 51), WithdrawConsequence::Frozen);
+
+    test::set_caller::<ink::env::DefaultEnvironment>(account);
+    assert_eq!(
+        contract.transfer(accounts.charlie, 51),
+        Err(Error::LiquidityRestrictions)
+    );
+    assert_eq!(contract.transfer(accounts.charlie, 50), Ok(()));
+}
+
+/// Test that holds do not count towards satisfying freezes in the legacy model.
+#[ink::test]
+fn holds_overlap_freezes_legacy() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = BalancesContract::new_with_legacy_freezes(10, 5);
+
+    let account = accounts.bob;
+    contract.mint(account, 100).unwrap();
+    contract.set_freeze(*b"staking_", account, 50).unwrap();
+
+    // Held funds cannot be taken from the frozen balance
+    assert_eq!(
+        contract.hold(*b"staking_", account, 45),
+        Err(Error::InsufficientBalance)
+    );
+    contract.hold(*b"staking_", account, 20).unwrap();
+
+    // The whole freeze applies to the free balance, with the minimum balance on top
+    assert_eq!(contract.usable_balance(account), 30);
+    assert_eq!(
+        contract.reducible_balance(account, Preservation::Preserve, Fortitude::Polite),
+        20
+    );
+    assert_eq!(contract.can_withdraw(account, 30), WithdrawConsequence::Success);
+    assert_eq!(contract.can_withdraw(account, 31), WithdrawConsequence::Frozen);
+
+    test::set_caller::<ink::env::DefaultEnvironment>(account);
+    assert_eq!(
+        contract.transfer(accounts.charlie, 31),
+        Err(Error::LiquidityRestrictions)
+    );
+    assert_eq!(contract.transfer(accounts.charlie, 30), Ok(()));
+}
+
+/// Test that reserving is not limited by freezes, since reserved funds still satisfy them.
+#[ink::test]
+fn reserve_with_freeze() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+
+    let account = accounts.bob;
+    contract.mint(account, 100).unwrap();
+    contract.set_freeze(*b"staking_", account, 60).unwrap();
+
+    assert_eq!(contract.reserve(account, 50), Ok(()));
+    assert_eq!(contract.usable_balance(account), 40);
+    assert_eq!(contract.can_withdraw(account, 40), WithdrawConsequence::Success);
+}
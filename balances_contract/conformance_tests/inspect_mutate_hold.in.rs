@@ -0,0 +1,491 @@
+/// Test the `hold` function for successfully holding funds for a reason.
+#[ink::test]
+fn hold_success() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+
+    let account = accounts.bob;
+    let reason = *b"staking_";
+    let initial_balance = contract.minimum_balance() + 50;
+    contract.mint(account, initial_balance).unwrap();
+
+    // Test: Hold part of the free balance
+    contract.hold(reason, account, 30).unwrap();
+
+    // Verify: Funds moved from free to the hold
+    assert_eq!(contract.balance_on_hold(reason, account), 30);
+    assert_eq!(contract.total_balance_on_hold(account), 30);
+    assert_eq!(contract.reserved_balance(account), 30);
+    assert_eq!(contract.balance(account), initial_balance - 30);
+    assert_eq!(contract.total_balance(account), initial_balance);
+
+    // Verify: Total issuance doesn't change
+    assert_eq!(contract.total_issuance(), initial_balance);
+}
+
+/// Test that holds for different reasons are tracked separately.
+#[ink::test]
+fn hold_multiple_reasons() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+
+    let account = accounts.bob;
+    let staking = *b"staking_";
+    let governance = *b"govern__";
+    contract.mint(account, 100).unwrap();
+
+    contract.hold(staking, account, 20).unwrap();
+    contract.hold(governance, account, 15).unwrap();
+    contract.hold(staking, account, 5).unwrap();
+
+    assert_eq!(contract.balance_on_hold(staking, account), 25);
+    assert_eq!(contract.balance_on_hold(governance, account), 15);
+    assert_eq!(contract.total_balance_on_hold(account), 40);
+    assert_eq!(contract.balance(account), 60);
+
+    // Releasing one reason leaves the other untouched
+    contract
+        .release(governance, account, 15, Precision::Exact)
+        .unwrap();
+    assert_eq!(contract.balance_on_hold(staking, account), 25);
+    assert_eq!(contract.balance_on_hold(governance, account), 0);
+    assert_eq!(contract.total_balance_on_hold(account), 25);
+    assert_eq!(contract.balance(account), 75);
+}
+
+/// Test that `hold` keeps the account alive and fails when funds are insufficient.
+#[ink::test]
+fn hold_insufficient_funds() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+
+    let account = accounts.bob;
+    let reason = *b"staking_";
+    contract.mint(account, 50).unwrap();
+
+    // Holding would reduce free balance below the minimum balance
+    assert!(!contract.can_hold(reason, account, 41));
+    assert_eq!(
+        contract.hold(reason, account, 41),
+        Err(Error::InsufficientBalance)
+    );
+
+    // Holding up to the minimum balance works
+    assert!(contract.can_hold(reason, account, 40));
+    contract.hold(reason, account, 40).unwrap();
+    assert_eq!(contract.balance(account), contract.minimum_balance());
+}
+
+/// Test that the number of distinct holds is bounded.
+#[ink::test]
+fn hold_too_many_holds() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 2, None);
+
+    let account = accounts.bob;
+    contract.mint(account, 100).unwrap();
+
+    contract.hold(*b"hold0001", account, 10).unwrap();
+    contract.hold(*b"hold0002", account, 10).unwrap();
+
+    assert!(!contract.can_hold(*b"hold0003", account, 10));
+    assert_eq!(
+        contract.hold(*b"hold0003", account, 10),
+        Err(Error::TooManyHolds)
+    );
+
+    // Existing reasons can still be increased
+    assert!(contract.can_hold(*b"hold0001", account, 10));
+    contract.hold(*b"hold0001", account, 10).unwrap();
+    assert_eq!(contract.balance_on_hold(*b"hold0001", account), 20);
+}
+
+/// Test `release` with `Precision::Exact` and `Precision::BestEffort`.
+#[ink::test]
+fn release_precision() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+
+    let account = accounts.bob;
+    let reason = *b"staking_";
+    contract.mint(account, 100).unwrap();
+    contract.hold(reason, account, 30).unwrap();
+
+    // Releasing more than held fails when Precision::Exact
+    assert_eq!(
+        contract.release(reason, account, 31, Precision::Exact),
+        Err(Error::InsufficientBalance)
+    );
+    assert_eq!(contract.balance_on_hold(reason, account), 30);
+
+    // Releasing part of the hold
+    let released = contract
+        .release(reason, account, 10, Precision::Exact)
+        .unwrap();
+    assert_eq!(released, 10);
+    assert_eq!(contract.balance_on_hold(reason, account), 20);
+    assert_eq!(contract.balance(account), 80);
+
+    // Releasing more than held releases everything when Precision::BestEffort
+    let released = contract
+        .release(reason, account, 50, Precision::BestEffort)
+        .unwrap();
+    assert_eq!(released, 20);
+    assert_eq!(contract.balance_on_hold(reason, account), 0);
+    assert_eq!(contract.total_balance_on_hold(account), 0);
+    assert_eq!(contract.balance(account), 100);
+}
+
+/// Test that `total_balance_on_hold` includes anonymous reserves.
+#[ink::test]
+fn total_balance_on_hold_includes_reserves() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+
+    let account = accounts.bob;
+    let reason = *b"staking_";
+    contract.mint(account, 100).unwrap();
+    contract.reserve(account, 10).unwrap();
+    contract.hold(reason, account, 20).unwrap();
+
+    assert_eq!(contract.balance_on_hold(reason, account), 20);
+    assert_eq!(contract.total_balance_on_hold(account), 30);
+    assert_eq!(contract.reserved_balance(account), 30);
+}
+
+/// Test holding and releasing zero amounts.
+#[ink::test]
+fn hold_release_zero() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+
+    let account = accounts.bob;
+    let reason = *b"staking_";
+
+    assert_eq!(contract.hold(reason, account, 0), Ok(()));
+    assert_eq!(
+        contract.release(reason, account, 0, Precision::Exact),
+        Ok(0)
+    );
+    assert_eq!(contract.balance_on_hold(reason, account), 0);
+}
+
+/// Test `set_balance_on_hold` for writing held balances directly.
+#[ink::test]
+fn set_balance_on_hold_success() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+
+    let account = accounts.bob;
+    let reason = *b"staking_";
+    contract.mint(account, 100).unwrap();
+
+    // Writing a hold does not touch the free balance but changes total issuance
+    contract.set_balance_on_hold(reason, account, 40).unwrap();
+    assert_eq!(contract.balance_on_hold(reason, account), 40);
+    assert_eq!(contract.reserved_balance(account), 40);
+    assert_eq!(contract.balance(account), 100);
+    assert_eq!(contract.total_issuance(), 140);
+
+    contract.set_balance_on_hold(reason, account, 15).unwrap();
+    assert_eq!(contract.balance_on_hold(reason, account), 15);
+    assert_eq!(contract.total_issuance(), 115);
+
+    // Writing zero removes the hold
+    contract.set_balance_on_hold(reason, account, 0).unwrap();
+    assert_eq!(contract.balance_on_hold(reason, account), 0);
+    assert_eq!(contract.total_balance_on_hold(account), 0);
+    assert_eq!(contract.total_issuance(), 100);
+
+    // Only the owner can write held balances
+    test::set_caller::<ink::env::DefaultEnvironment>(account);
+    assert_eq!(
+        contract.set_balance_on_hold(reason, account, 10),
+        Err(Error::NotAllowed)
+    );
+}
+
+/// Test `increase_balance_on_hold` and `decrease_balance_on_hold` with both precisions.
+#[ink::test]
+fn increase_decrease_balance_on_hold() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+
+    let account = accounts.bob;
+    let reason = *b"staking_";
+    contract.mint(account, 100).unwrap();
+    contract.hold(reason, account, 20).unwrap();
+
+    assert_eq!(
+        contract.increase_balance_on_hold(reason, account, 30, Precision::Exact),
+        Ok(30)
+    );
+    assert_eq!(contract.balance_on_hold(reason, account), 50);
+    assert_eq!(contract.balance(account), 80);
+    assert_eq!(contract.total_issuance(), 130);
+
+    // Decreasing more than held fails when Precision::Exact
+    assert_eq!(
+        contract.decrease_balance_on_hold(reason, account, 51, Precision::Exact),
+        Err(Error::InsufficientBalance)
+    );
+    assert_eq!(
+        contract.decrease_balance_on_hold(reason, account, 10, Precision::Exact),
+        Ok(10)
+    );
+    assert_eq!(
+        contract.decrease_balance_on_hold(reason, account, 100, Precision::BestEffort),
+        Ok(40)
+    );
+    assert_eq!(contract.balance_on_hold(reason, account), 0);
+    assert_eq!(contract.total_issuance(), 80);
+
+    // Increasing beyond the max value saturates when Precision::BestEffort
+    contract.set_total_issuance(Balance::MAX - 5).unwrap();
+    assert_eq!(
+        contract.increase_balance_on_hold(reason, account, 10, Precision::Exact),
+        Err(Error::Overflow)
+    );
+    assert_eq!(
+        contract.increase_balance_on_hold(reason, account, 10, Precision::BestEffort),
+        Ok(5)
+    );
+    assert_eq!(contract.balance_on_hold(reason, account), 5);
+    assert_eq!(contract.total_issuance(), Balance::MAX);
+}
+
+/// Test that writing held balances respects the hold limit.
+#[ink::test]
+fn set_balance_on_hold_too_many_holds() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 1, None);
+
+    let account = accounts.bob;
+    contract.mint(account, 100).unwrap();
+    contract
+        .set_balance_on_hold(*b"hold0001", account, 10)
+        .unwrap();
+
+    assert_eq!(
+        contract.set_balance_on_hold(*b"hold0002", account, 10),
+        Err(Error::TooManyHolds)
+    );
+    assert_eq!(
+        contract.increase_balance_on_hold(*b"hold0002", account, 10, Precision::Exact),
+        Err(Error::TooManyHolds)
+    );
+    assert_eq!(contract.total_issuance(), 110);
+}
+
+/// Test `transfer_on_hold` into the free balance and into a hold.
+#[ink::test]
+fn transfer_on_hold_success() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+
+    let source = accounts.bob;
+    let dest = accounts.charlie;
+    let reason = *b"escrow__";
+    contract.mint(source, 100).unwrap();
+    contract.mint(dest, 100).unwrap();
+    contract.hold(reason, source, 50).unwrap();
+
+    // Held funds arrive in the free balance
+    let moved = contract
+        .transfer_on_hold(
+            reason,
+            source,
+            dest,
+            20,
+            Precision::Exact,
+            Restriction::Free,
+            Fortitude::Polite,
+        )
+        .unwrap();
+    assert_eq!(moved, 20);
+    assert_eq!(contract.balance_on_hold(reason, source), 30);
+    assert_eq!(contract.balance(dest), 120);
+
+    // Held funds stay on hold for the same reason
+    let moved = contract
+        .transfer_on_hold(
+            reason,
+            source,
+            dest,
+            100,
+            Precision::BestEffort,
+            Restriction::OnHold,
+            Fortitude::Polite,
+        )
+        .unwrap();
+    assert_eq!(moved, 30);
+    assert_eq!(contract.balance_on_hold(reason, source), 0);
+    assert_eq!(contract.reserved_balance(source), 0);
+    assert_eq!(contract.balance_on_hold(reason, dest), 30);
+    assert_eq!(contract.reserved_balance(dest), 30);
+
+    // Total issuance is unchanged
+    assert_eq!(contract.total_issuance(), 200);
+}
+
+/// Test the restrictions of `transfer_on_hold`.
+#[ink::test]
+fn transfer_on_hold_restrictions() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+
+    let source = accounts.bob;
+    let dest = accounts.charlie;
+    let reason = *b"escrow__";
+    contract.mint(source, 100).unwrap();
+    contract.hold(reason, source, 50).unwrap();
+
+    // More than held
+    assert_eq!(
+        contract.transfer_on_hold(
+            reason,
+            source,
+            dest,
+            60,
+            Precision::Exact,
+            Restriction::Free,
+            Fortitude::Polite,
+        ),
+        Err(Error::InsufficientBalance)
+    );
+
+    // Below the minimum balance of a new account
+    assert_eq!(
+        contract.transfer_on_hold(
+            reason,
+            source,
+            dest,
+            5,
+            Precision::Exact,
+            Restriction::Free,
+            Fortitude::Polite,
+        ),
+        Err(Error::ExistentialDeposit)
+    );
+
+    // Only a trusted caller can move held funds
+    test::set_caller::<ink::env::DefaultEnvironment>(dest);
+    assert_eq!(
+        contract.transfer_on_hold(
+            reason,
+            source,
+            dest,
+            20,
+            Precision::Exact,
+            Restriction::Free,
+            Fortitude::Polite,
+        ),
+        Err(Error::NotAllowed)
+    );
+    assert_eq!(contract.balance_on_hold(reason, source), 50);
+}
+
+/// Test `transfer_and_hold` for moving free funds onto a hold of another account.
+#[ink::test]
+fn transfer_and_hold_success() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+
+    let source = accounts.bob;
+    let dest = accounts.charlie;
+    let reason = *b"deposit_";
+    contract.mint(source, 100).unwrap();
+    contract.mint(dest, 100).unwrap();
+    contract.set_lock(source, *b"testlock", 30).unwrap();
+
+    // Frozen funds cannot be moved
+    assert_eq!(
+        contract.transfer_and_hold(
+            reason,
+            source,
+            dest,
+            80,
+            Precision::Exact,
+            Preservation::Expendable,
+            Fortitude::Polite,
+        ),
+        Err(Error::LiquidityRestrictions)
+    );
+
+    // The source is kept alive when asked to, the frozen funds covering the minimum balance
+    let moved = contract
+        .transfer_and_hold(
+            reason,
+            source,
+            dest,
+            100,
+            Precision::BestEffort,
+            Preservation::Preserve,
+            Fortitude::Polite,
+        )
+        .unwrap();
+    assert_eq!(moved, 70);
+    assert_eq!(contract.balance(source), 30);
+    assert_eq!(contract.balance_on_hold(reason, dest), 70);
+    assert_eq!(contract.balance(dest), 100);
+    assert_eq!(contract.total_issuance(), 200);
+
+    // Holds cannot be placed on another account without a trusted caller
+    test::set_caller::<ink::env::DefaultEnvironment>(source);
+    assert_eq!(
+        contract.transfer_and_hold(
+            reason,
+            source,
+            dest,
+            10,
+            Precision::Exact,
+            Preservation::Expendable,
+            Fortitude::Polite,
+        ),
+        Err(Error::NotAllowed)
+    );
+    assert_eq!(contract.balance_on_hold(reason, dest), 70);
+}
+
+/// Test that `transfer_and_hold` handles the dust of the source account.
+#[ink::test]
+fn transfer_and_hold_dust() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+
+    let source = accounts.bob;
+    let dest = accounts.charlie;
+    let reason = *b"deposit_";
+    contract.mint(source, 100).unwrap();
+    contract.mint(dest, 100).unwrap();
+
+    let moved = contract
+        .transfer_and_hold(
+            reason,
+            source,
+            dest,
+            95,
+            Precision::Exact,
+            Preservation::Expendable,
+            Fortitude::Polite,
+        )
+        .unwrap();
+    assert_eq!(moved, 95);
+    assert_eq!(contract.balance(source), 0);
+    assert_eq!(contract.balance_on_hold(reason, dest), 95);
+    assert_eq!(contract.total_issuance(), 195);
+}
@@ -0,0 +1,102 @@
+/// Test the maximum supply is enforced by every issuance path
+#[ink::test]
+fn max_supply_caps_issuance() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = BalancesContract::new_with_max_supply(10, 5, 100);
+    assert_eq!(contract.max_supply(), Some(100));
+
+    contract.mint(accounts.bob, 60).unwrap();
+    assert_eq!(
+        contract.can_deposit(accounts.bob, 50, Provenance::Minted),
+        DepositConsequence::ExceedsMaxSupply
+    );
+    assert_eq!(
+        contract.can_deposit(accounts.bob, 50, Provenance::Extant),
+        DepositConsequence::Success
+    );
+    assert_eq!(
+        contract.mint(accounts.bob, 50),
+        Err(Error::MaxSupplyExceeded)
+    );
+    assert_eq!(
+        contract.increase_balance(accounts.bob, 50, Precision::Exact),
+        Err(Error::MaxSupplyExceeded)
+    );
+    assert_eq!(
+        contract.increase_balance(accounts.bob, 50, Precision::BestEffort),
+        Ok(40)
+    );
+    assert_eq!(contract.total_issuance(), 100);
+
+    assert_eq!(
+        contract.restore(accounts.bob, 10),
+        Err(Error::MaxSupplyExceeded)
+    );
+    assert_eq!(contract.set_balance(accounts.bob, 1_000), Ok(100));
+    assert_eq!(
+        contract.write_balance(accounts.bob, 1_000),
+        Err(Error::MaxSupplyExceeded)
+    );
+    assert_eq!(
+        contract.set_total_issuance(101),
+        Err(Error::MaxSupplyExceeded)
+    );
+    assert_eq!(contract.issue(10).unwrap().amount, 0);
+
+    assert_eq!(contract.balance(accounts.bob), 100);
+    assert_eq!(contract.total_issuance(), 100);
+}
+
+/// Test only root may change the maximum supply, and never below total issuance
+#[ink::test]
+fn set_max_supply_rules() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+    assert_eq!(contract.max_supply(), None);
+    contract.mint(accounts.bob, 100).unwrap();
+
+    assert_eq!(
+        contract.set_max_supply(Some(99)),
+        Err(Error::MaxSupplyExceeded)
+    );
+    contract.set_max_supply(Some(150)).unwrap();
+    assert_eq!(
+        contract.mint(accounts.bob, 51),
+        Err(Error::MaxSupplyExceeded)
+    );
+
+    contract.grant_role(Role::Admin, accounts.bob).unwrap();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+    assert_eq!(contract.set_max_supply(None), Err(Error::NotAllowed));
+
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    contract.set_max_supply(None).unwrap();
+    contract.mint(accounts.bob, 51).unwrap();
+    assert_eq!(contract.total_issuance(), 151);
+}
+
+/// Test dropping deposit debts cannot push total issuance past the maximum supply
+#[ink::test]
+fn max_supply_caps_dropped_debts() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = BalancesContract::new_with_max_supply(10, 5, 100);
+
+    let first = contract
+        .deposit(accounts.bob, 60, Precision::Exact)
+        .unwrap();
+    let second = contract
+        .deposit(accounts.charlie, 60, Precision::Exact)
+        .unwrap();
+    contract.drop_debt(first).unwrap();
+    assert_eq!(contract.total_issuance(), 60);
+    assert_eq!(
+        contract.drop_debt(second),
+        Err(Error::MaxSupplyExceeded)
+    );
+    assert_eq!(contract.total_issuance(), 60);
+
+    assert_eq!(contract.pair(50), Err(Error::MaxSupplyExceeded));
+}
@@ -0,0 +1,209 @@
+/// Test minting is charged against the minter's allowance
+#[ink::test]
+fn minter_allowance_is_charged() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+    contract.grant_role(Role::Minter, accounts.bob).unwrap();
+    contract.grant_role(Role::Minter, accounts.charlie).unwrap();
+    contract.set_minter_quota(accounts.bob, 100, 0).unwrap();
+
+    assert_eq!(contract.remaining_quota(accounts.alice), Balance::MAX);
+    assert_eq!(contract.remaining_quota(accounts.bob), 100);
+    assert_eq!(contract.remaining_quota(accounts.charlie), 0);
+
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+    contract.mint(accounts.django, 60).unwrap();
+    assert_eq!(contract.remaining_quota(accounts.bob), 40);
+    assert_eq!(
+        contract.mint(accounts.django, 50),
+        Err(Error::MinterQuotaExceeded)
+    );
+    assert_eq!(contract.balance(accounts.django), 60);
+    contract.mint(accounts.django, 40).unwrap();
+    assert_eq!(contract.remaining_quota(accounts.bob), 0);
+    assert_eq!(contract.minter_quota(accounts.bob).unwrap().allowance, 0);
+
+    // A minter without a quota may not mint at all
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+    assert_eq!(
+        contract.mint(accounts.django, 10),
+        Err(Error::MinterQuotaExceeded)
+    );
+    assert_eq!(contract.total_issuance(), 100);
+}
+
+/// Test the window cap limits minting until the window of blocks has passed
+#[ink::test]
+fn minter_window_cap_resets() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+    contract.grant_role(Role::Minter, accounts.bob).unwrap();
+    contract.set_quota_window(10).unwrap();
+    contract.set_minter_quota(accounts.bob, 1_000, 100).unwrap();
+    assert_eq!(contract.quota_window(), 10);
+
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+    contract.mint(accounts.charlie, 100).unwrap();
+    assert_eq!(contract.remaining_quota(accounts.bob), 0);
+    assert_eq!(
+        contract.mint(accounts.charlie, 1),
+        Err(Error::MinterQuotaExceeded)
+    );
+
+    for _ in 0..10 {
+        test::advance_block::<ink::env::DefaultEnvironment>();
+    }
+    assert_eq!(contract.remaining_quota(accounts.bob), 100);
+    contract.mint(accounts.charlie, 100).unwrap();
+    assert_eq!(contract.minter_quota(accounts.bob).unwrap().allowance, 800);
+    assert_eq!(contract.balance(accounts.charlie), 200);
+}
+
+/// Test increase_balance and issue are charged against the caller's quota
+#[ink::test]
+fn unbalanced_and_credit_deposits_are_charged() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+    contract.set_trusted(accounts.charlie, true).unwrap();
+    contract.grant_role(Role::Minter, accounts.charlie).unwrap();
+    contract.set_minter_quota(accounts.charlie, 50, 0).unwrap();
+
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+    assert_eq!(
+        contract.increase_balance(accounts.bob, 30, Precision::Exact),
+        Ok(30)
+    );
+    assert_eq!(
+        contract.increase_balance(accounts.bob, 30, Precision::Exact),
+        Err(Error::MinterQuotaExceeded)
+    );
+
+    let credit = contract.issue(20).unwrap();
+    assert_eq!(contract.remaining_quota(accounts.charlie), 0);
+    contract.resolve_credit(accounts.bob, credit).unwrap();
+
+    // An exhausted quota cannot issue credits that raise total issuance
+    assert_eq!(contract.issue(10), Err(Error::MinterQuotaExceeded));
+    assert_eq!(contract.balance(accounts.bob), 50);
+    assert_eq!(contract.total_issuance(), 50);
+}
+
+/// Test cancelling an issued credit releases its charge on the quota
+#[ink::test]
+fn cancelled_credits_release_quota() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+    contract.grant_role(Role::Minter, accounts.charlie).unwrap();
+    contract.grant_role(Role::Burner, accounts.charlie).unwrap();
+    contract.set_minter_quota(accounts.charlie, 50, 0).unwrap();
+
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+    let credit = contract.issue(50).unwrap();
+    assert_eq!(contract.remaining_quota(accounts.charlie), 0);
+    contract.drop_credit(credit).unwrap();
+    assert_eq!(contract.remaining_quota(accounts.charlie), 50);
+    assert_eq!(contract.total_issuance(), 0);
+
+    // Offsetting against a rescinded debt cancels the issued tokens
+    let credit = contract.issue(50).unwrap();
+    let debt = contract.rescind(30).unwrap();
+    let SameOrOther::Same(excess) = contract.offset(credit, debt).unwrap() else {
+        panic!("expected the credit to be left over");
+    };
+    assert_eq!(contract.remaining_quota(accounts.charlie), 30);
+    contract.resolve_credit(accounts.bob, excess).unwrap();
+    assert_eq!(contract.remaining_quota(accounts.charlie), 30);
+    assert_eq!(contract.balance(accounts.bob), 20);
+    assert_eq!(contract.total_issuance(), 20);
+}
+
+/// Test deposits are charged against the caller's quota before their debt is dropped
+#[ink::test]
+fn deposit_is_charged() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+    contract.grant_role(Role::Minter, accounts.charlie).unwrap();
+    contract.set_minter_quota(accounts.charlie, 50, 0).unwrap();
+
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+    let debt = contract
+        .deposit(accounts.bob, 40, Precision::Exact)
+        .unwrap();
+    assert_eq!(contract.remaining_quota(accounts.charlie), 10);
+    assert_eq!(
+        contract.deposit(accounts.bob, 20, Precision::Exact),
+        Err(Error::MinterQuotaExceeded)
+    );
+    assert_eq!(contract.balance(accounts.bob), 40);
+
+    contract.drop_debt(debt).unwrap();
+    assert_eq!(contract.total_issuance(), 40);
+}
+
+/// Test only credits issued as new tokens are charged against a quota
+#[ink::test]
+fn withdrawn_credits_are_not_charged() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+    contract.mint(accounts.bob, 100).unwrap();
+    contract.grant_role(Role::Minter, accounts.charlie).unwrap();
+    contract.set_minter_quota(accounts.charlie, 20, 0).unwrap();
+
+    // A non-minter can move its own funds through a credit
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+    let credit = contract
+        .withdraw(
+            accounts.bob,
+            60,
+            Precision::Exact,
+            Preservation::Expendable,
+            Fortitude::Polite,
+        )
+        .unwrap();
+    contract.resolve_credit(accounts.django, credit).unwrap();
+    assert_eq!(contract.balance(accounts.django), 60);
+    assert_eq!(contract.total_issuance(), 100);
+
+    // The issued parts of a split credit keep their charge until they are cancelled
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+    let issued = contract.issue(20).unwrap();
+    let (first, second) = contract.split_credit(issued, 15).unwrap();
+    contract.resolve_credit(accounts.eve, first).unwrap();
+    assert_eq!(contract.remaining_quota(accounts.charlie), 0);
+    contract.drop_credit(second).unwrap();
+    assert_eq!(contract.remaining_quota(accounts.charlie), 5);
+    assert_eq!(contract.balance(accounts.eve), 15);
+}
+
+/// Test restore and set_balance are blocked once the caller's quota is exhausted
+#[ink::test]
+fn restore_and_set_balance_are_charged() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+    contract.set_trusted(accounts.charlie, true).unwrap();
+    contract.set_minter_quota(accounts.charlie, 50, 0).unwrap();
+
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+    contract.restore(accounts.bob, 30).unwrap();
+    assert_eq!(contract.set_balance(accounts.bob, 50), Ok(50));
+    assert_eq!(contract.remaining_quota(accounts.charlie), 0);
+
+    assert_eq!(
+        contract.restore(accounts.bob, 10),
+        Err(Error::MinterQuotaExceeded)
+    );
+    assert_eq!(contract.set_balance(accounts.bob, 60), Ok(50));
+    assert_eq!(contract.balance(accounts.bob), 50);
+    assert_eq!(contract.total_issuance(), 50);
+
+    // Lowering the balance burns and needs no quota
+    assert_eq!(contract.set_balance(accounts.bob, 20), Ok(20));
+    assert_eq!(contract.total_issuance(), 20);
+}
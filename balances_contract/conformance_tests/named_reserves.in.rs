@@ -0,0 +1,114 @@
+/// Test named reserves are tracked separately from the anonymous reserve
+#[ink::test]
+fn reserve_named_success() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+
+    let account = accounts.bob;
+    contract.mint(account, 100).unwrap();
+    contract.reserve_named(*b"staking_", account, 30).unwrap();
+    contract.reserve_named(*b"staking_", account, 10).unwrap();
+    contract.reserve_named(*b"democrac", account, 20).unwrap();
+    contract.reserve(account, 5).unwrap();
+
+    assert_eq!(contract.reserved_balance_named(*b"staking_", account), 40);
+    assert_eq!(contract.reserved_balance_named(*b"democrac", account), 20);
+    assert_eq!(contract.reserved_balance(account), 65);
+    assert_eq!(contract.balance(account), 35);
+
+    // Anonymous unreserve does not touch named reserves
+    assert_eq!(contract.unreserve(account, 50), Ok(5));
+    assert_eq!(contract.reserved_balance(account), 60);
+
+    assert_eq!(contract.unreserve_named(*b"staking_", account, 50), Ok(40));
+    assert_eq!(contract.reserved_balance_named(*b"staking_", account), 0);
+    assert_eq!(contract.reserved_balance(account), 20);
+    assert_eq!(contract.balance(account), 80);
+}
+
+/// Test reserve_named respects the reserve limit and the usable balance
+#[ink::test]
+fn reserve_named_limits() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 1, None);
+
+    let account = accounts.bob;
+    contract.mint(account, 100).unwrap();
+    contract.reserve_named(*b"staking_", account, 30).unwrap();
+
+    assert_eq!(
+        contract.reserve_named(*b"democrac", account, 10),
+        Err(Error::TooManyReserves)
+    );
+    assert_eq!(
+        contract.reserve_named(*b"staking_", account, 71),
+        Err(Error::InsufficientBalance)
+    );
+    assert_eq!(contract.reserved_balance_named(*b"staking_", account), 30);
+    assert_eq!(contract.reserved_balance(account), 30);
+}
+
+/// Test slash_reserved_named only slashes the named reserve
+#[ink::test]
+fn slash_reserved_named_success() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+
+    let account = accounts.bob;
+    contract.mint(account, 100).unwrap();
+    contract.reserve_named(*b"staking_", account, 30).unwrap();
+    contract.reserve(account, 20).unwrap();
+
+    let (credit, remaining) = contract
+        .slash_reserved_named(*b"staking_", account, 50)
+        .unwrap();
+    assert_eq!(credit.peek(), 30);
+    assert_eq!(remaining, 20);
+    assert_eq!(contract.reserved_balance_named(*b"staking_", account), 0);
+    assert_eq!(contract.reserved_balance(account), 20);
+
+    // Anonymous slashes leave named reserves alone
+    contract.reserve_named(*b"staking_", account, 10).unwrap();
+    let (credit, remaining) = contract.slash_reserved(account, 50).unwrap();
+    assert_eq!(credit.peek(), 20);
+    assert_eq!(remaining, 30);
+    assert_eq!(contract.reserved_balance(account), 10);
+}
+
+/// Test repatriate_reserved_named moves funds into the same named reserve
+#[ink::test]
+fn repatriate_reserved_named_success() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+
+    let from = accounts.bob;
+    let to = accounts.charlie;
+    contract.mint(from, 100).unwrap();
+    contract.reserve_named(*b"staking_", from, 50).unwrap();
+
+    assert_eq!(
+        contract.repatriate_reserved_named(*b"staking_", from, to, 10, BalanceStatus::Free),
+        Err(Error::DeadAccount)
+    );
+
+    contract.mint(to, 100).unwrap();
+    assert_eq!(
+        contract.repatriate_reserved_named(*b"staking_", from, to, 30, BalanceStatus::Reserved),
+        Ok(0)
+    );
+    assert_eq!(contract.reserved_balance_named(*b"staking_", from), 20);
+    assert_eq!(contract.reserved_balance_named(*b"staking_", to), 30);
+    assert_eq!(contract.reserved_balance(to), 30);
+
+    assert_eq!(
+        contract.repatriate_reserved_named(*b"staking_", from, to, 30, BalanceStatus::Free),
+        Ok(10)
+    );
+    assert_eq!(contract.reserved_balance(from), 0);
+    assert_eq!(contract.balance(to), 120);
+    assert_eq!(contract.total_issuance(), 200);
+}
@@ -0,0 +1,151 @@
+/// Test root-only messages reject every caller but the owner, trusted or not
+#[ink::test]
+fn root_messages_reject_other_callers() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+    contract.mint(accounts.bob, 100).unwrap();
+    contract.set_trusted(accounts.charlie, true).unwrap();
+
+    for caller in [accounts.bob, accounts.charlie] {
+        test::set_caller::<ink::env::DefaultEnvironment>(caller);
+        assert_eq!(contract.mint(accounts.bob, 10), Err(Error::NotAllowed));
+        assert_eq!(
+            contract.write_balance(accounts.bob, 10),
+            Err(Error::NotAllowed)
+        );
+        assert_eq!(contract.set_total_issuance(10), Err(Error::NotAllowed));
+        assert_eq!(contract.deactivate(10), Err(Error::NotAllowed));
+        assert_eq!(contract.issue(10), Err(Error::NotAllowed));
+        assert_eq!(contract.slash(accounts.bob, 10), Err(Error::NotAllowed));
+        assert_eq!(contract.set_dust_trap(Some(caller)), Err(Error::NotAllowed));
+        assert_eq!(contract.set_trusted(caller, true), Err(Error::NotAllowed));
+    }
+
+    assert_eq!(contract.balance(accounts.bob), 100);
+    assert_eq!(contract.total_issuance(), 100);
+}
+
+/// Test trusted-caller messages reject untrusted callers, even on their own account
+#[ink::test]
+fn trusted_messages_reject_untrusted_callers() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+    contract.mint(accounts.bob, 100).unwrap();
+
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+    let who = accounts.bob;
+    assert_eq!(
+        contract.burn_from(
+            who,
+            10,
+            Preservation::Expendable,
+            Precision::Exact,
+            Fortitude::Polite
+        ),
+        Err(Error::NotAllowed)
+    );
+    assert_eq!(
+        contract.increase_balance(who, 10, Precision::Exact),
+        Err(Error::NotAllowed)
+    );
+    assert_eq!(contract.restore(who, 10), Err(Error::NotAllowed));
+    assert_eq!(contract.shelve(who, 10), Err(Error::NotAllowed));
+    assert_eq!(contract.reserve(who, 10), Err(Error::NotAllowed));
+    assert_eq!(contract.hold([1; 8], who, 10), Err(Error::NotAllowed));
+    assert_eq!(contract.set_freeze([1; 8], who, 10), Err(Error::NotAllowed));
+    assert_eq!(contract.set_lock(who, [1; 8], 10), Err(Error::NotAllowed));
+    assert_eq!(contract.remove_lock(who, [1; 8]), Err(Error::NotAllowed));
+    assert_eq!(contract.set_balance(who, 1_000), Err(Error::NotAllowed));
+
+    assert_eq!(contract.balance(accounts.bob), 100);
+    assert_eq!(contract.total_issuance(), 100);
+    assert_eq!(contract.reserved_balance(accounts.bob), 0);
+}
+
+/// Test registered trusted callers may move funds until they are removed
+#[ink::test]
+fn registered_trusted_caller() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+    contract.mint(accounts.bob, 100).unwrap();
+
+    assert!(contract.is_trusted(accounts.alice));
+    assert!(!contract.is_trusted(accounts.charlie));
+    contract.set_trusted(accounts.charlie, true).unwrap();
+    assert!(contract.is_trusted(accounts.charlie));
+
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+    contract.reserve(accounts.bob, 30).unwrap();
+    assert_eq!(contract.reserved_balance(accounts.bob), 30);
+
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    contract.set_trusted(accounts.charlie, false).unwrap();
+    assert!(!contract.is_trusted(accounts.charlie));
+
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+    assert_eq!(contract.unreserve(accounts.bob, 30), Err(Error::NotAllowed));
+    assert_eq!(contract.reserved_balance(accounts.bob), 30);
+}
+
+/// Test signed messages accept the account itself and reject other untrusted callers
+#[ink::test]
+fn signed_messages_require_account_or_trusted() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+    contract.mint(accounts.bob, 100).unwrap();
+    contract.hold([1; 8], accounts.bob, 40).unwrap();
+
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+    assert_eq!(
+        contract.currency_withdraw(
+            accounts.bob,
+            10,
+            WithdrawReasons::all(),
+            ExistenceRequirement::KeepAlive
+        ),
+        Err(Error::NotAllowed)
+    );
+    assert_eq!(
+        contract.transfer_on_hold(
+            [1; 8],
+            accounts.bob,
+            accounts.charlie,
+            10,
+            Precision::Exact,
+            Restriction::Free,
+            Fortitude::Polite
+        ),
+        Err(Error::NotAllowed)
+    );
+
+    // The account holder needs no trust to withdraw, but cannot move its own held funds
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+    assert_eq!(
+        contract.currency_withdraw(
+            accounts.bob,
+            10,
+            WithdrawReasons::all(),
+            ExistenceRequirement::KeepAlive
+        ),
+        Ok(10)
+    );
+    assert_eq!(
+        contract.transfer_on_hold(
+            [1; 8],
+            accounts.bob,
+            accounts.charlie,
+            20,
+            Precision::Exact,
+            Restriction::Free,
+            Fortitude::Polite
+        ),
+        Err(Error::NotAllowed)
+    );
+    assert_eq!(contract.balance(accounts.bob), 50);
+    assert_eq!(contract.balance_on_hold([1; 8], accounts.bob), 40);
+    assert_eq!(contract.balance(accounts.charlie), 0);
+}
@@ -0,0 +1,107 @@
+/// Test an account is removed from storage together with its locks when it is reaped
+#[ink::test]
+fn reaping_removes_account_and_locks() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+    assert!(!contract.account_exists(accounts.bob));
+
+    contract.mint(accounts.bob, 100).unwrap();
+    contract.set_lock(accounts.bob, [1; 8], 30).unwrap();
+    assert!(contract.account_exists(accounts.bob));
+
+    let events_before = test::recorded_events().count();
+    contract.slash(accounts.bob, 100).unwrap();
+    // Slashed and Killed
+    assert_eq!(test::recorded_events().count(), events_before + 2);
+    assert!(!contract.account_exists(accounts.bob));
+    assert!(!contract.accounts.contains((NATIVE_ASSET, accounts.bob)));
+    assert!(contract.locks(accounts.bob).is_empty());
+}
+
+/// Test Endowed is only emitted when an account is created
+#[ink::test]
+fn endowed_only_on_creation() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+
+    contract.mint(accounts.bob, 50).unwrap();
+    assert_eq!(test::recorded_events().count(), 1);
+    contract.mint(accounts.bob, 50).unwrap();
+    assert_eq!(test::recorded_events().count(), 1);
+
+    // Transferring everything kills the sender and endows the recipient
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+    contract.transfer(accounts.charlie, 100).unwrap();
+    assert_eq!(test::recorded_events().count(), 4);
+    assert!(!contract.account_exists(accounts.bob));
+    assert!(contract.account_exists(accounts.charlie));
+    assert!(!contract.accounts.contains((NATIVE_ASSET, accounts.bob)));
+}
+
+/// Test references keep an account without balance in existence
+#[ink::test]
+fn refs_keep_account_alive() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+    contract.mint(accounts.bob, 100).unwrap();
+    contract.inc_sufficients(accounts.bob).unwrap();
+
+    contract
+        .burn_from(
+            accounts.bob,
+            100,
+            Preservation::Expendable,
+            Precision::Exact,
+            Fortitude::Polite,
+        )
+        .unwrap();
+    assert!(contract.account_exists(accounts.bob));
+    assert!(contract.accounts.contains((NATIVE_ASSET, accounts.bob)));
+
+    contract.dec_sufficients(accounts.bob).unwrap();
+    assert!(!contract.account_exists(accounts.bob));
+}
+
+/// Test removing a lock or freeze of an unknown account stores nothing
+#[ink::test]
+fn unknown_account_locks_store_nothing() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+
+    contract.remove_lock(accounts.bob, [1; 8]).unwrap();
+    contract.thaw([1; 8], accounts.bob).unwrap();
+    assert!(!contract.accounts.contains((NATIVE_ASSET, accounts.bob)));
+    assert!(!contract.account_exists(accounts.bob));
+}
+
+/// Test a lock or freeze set on an unfunded account still applies once it is funded
+#[ink::test]
+fn unfunded_account_locks_apply_once_funded() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+
+    contract.set_lock(accounts.bob, [1; 8], 50).unwrap();
+    contract.set_freeze([1; 8], accounts.charlie, 50).unwrap();
+    assert_eq!(contract.locks(accounts.bob).len(), 1);
+    assert_eq!(contract.balance_frozen([1; 8], accounts.charlie), 50);
+
+    contract.mint(accounts.bob, 100).unwrap();
+    contract.mint(accounts.charlie, 100).unwrap();
+
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+    assert_eq!(
+        contract.transfer(accounts.django, 60),
+        Err(Error::LiquidityRestrictions)
+    );
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+    assert_eq!(
+        contract.transfer(accounts.django, 60),
+        Err(Error::LiquidityRestrictions)
+    );
+    assert_eq!(contract.transfer(accounts.django, 50), Ok(()));
+}
@@ -215,4 +215,169 @@ fn balanced_pair() {
     let distance_from_max_value = 5;
     contract.set_total_issuance(max_value - distance_from_max_value).unwrap();
     assert!(contract.pair(distance_from_max_value + 5).is_err());
-}
\ No newline at end of file
+}
+/// Test that issued credit is counted in total issuance until it is dropped.
+#[ink::test]
+fn balanced_issue() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+
+    let account = accounts.bob;
+
+    let credit = contract.issue(100).unwrap();
+    assert_eq!(credit.peek(), 100);
+    assert_eq!(contract.total_issuance(), 100);
+    assert_eq!(contract.active_issuance(), 100);
+
+    // Resolving the credit does not change total issuance
+    contract.resolve_credit(account, credit).unwrap();
+    assert_eq!(contract.balance(account), 100);
+    assert_eq!(contract.total_issuance(), 100);
+
+    // Dropping an unresolved credit removes it from total issuance
+    let credit = contract.issue(40).unwrap();
+    assert_eq!(contract.total_issuance(), 140);
+    contract.drop_credit(credit).unwrap();
+    assert_eq!(contract.total_issuance(), 100);
+
+    // Issuance saturates at the maximum balance
+    contract.set_total_issuance(Balance::MAX - 5).unwrap();
+    let credit = contract.issue(10).unwrap();
+    assert_eq!(credit.peek(), 5);
+    assert_eq!(contract.total_issuance(), Balance::MAX);
+}
+
+/// Test that rescinded debt is removed from total issuance until it is dropped.
+#[ink::test]
+fn balanced_rescind() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+
+    let account = accounts.bob;
+    contract.mint(account, 100).unwrap();
+
+    let debt = contract.rescind(30).unwrap();
+    assert_eq!(debt.peek(), 30);
+    assert_eq!(contract.total_issuance(), 70);
+
+    // Settling the debt does not change total issuance
+    let remaining = contract
+        .settle_debt(account, debt, Preservation::Expendable)
+        .unwrap();
+    assert_eq!(remaining.peek(), 0);
+    assert_eq!(contract.balance(account), 70);
+    assert_eq!(contract.total_issuance(), 70);
+
+    // Dropping an unsettled debt adds it back to total issuance
+    let debt = contract.rescind(20).unwrap();
+    assert_eq!(contract.total_issuance(), 50);
+    contract.drop_debt(debt).unwrap();
+    assert_eq!(contract.total_issuance(), 70);
+
+    // Rescinding more than total issuance saturates at zero
+    let debt = contract.rescind(1_000).unwrap();
+    assert_eq!(debt.peek(), 70);
+    assert_eq!(contract.total_issuance(), 0);
+}
+
+/// Test depositing into an account against a debt.
+#[ink::test]
+fn balanced_deposit_debt() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+
+    let account = accounts.bob;
+
+    // Deposits below the minimum balance cannot create an account
+    assert_eq!(
+        contract.deposit(account, 5, Precision::Exact),
+        Err(Error::ExistentialDeposit)
+    );
+    let debt = contract.deposit(account, 5, Precision::BestEffort).unwrap();
+    assert_eq!(debt.peek(), 0);
+
+    // Total issuance only changes once the debt is dropped
+    let debt = contract.deposit(account, 50, Precision::Exact).unwrap();
+    assert_eq!(debt.peek(), 50);
+    assert_eq!(contract.balance(account), 50);
+    assert_eq!(contract.total_issuance(), 0);
+    contract.drop_debt(debt).unwrap();
+    assert_eq!(contract.total_issuance(), 50);
+
+    // A deposit can be offset by an explicit withdrawal from another account
+    let other = accounts.charlie;
+    let debt = contract.deposit(other, 20, Precision::Exact).unwrap();
+    let remaining = contract
+        .settle_debt(account, debt, Preservation::Preserve)
+        .unwrap();
+    assert_eq!(remaining.peek(), 0);
+    assert_eq!(contract.balance(account), 30);
+    assert_eq!(contract.balance(other), 20);
+    assert_eq!(contract.total_issuance(), 50);
+
+    // Only the owner can deposit
+    test::set_caller::<ink::env::DefaultEnvironment>(account);
+    assert_eq!(
+        contract.deposit(account, 50, Precision::Exact),
+        Err(Error::NotAllowed)
+    );
+}
+
+/// Test withdrawing from an account as a credit.
+#[ink::test]
+fn balanced_withdraw_credit() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+
+    let account = accounts.bob;
+    contract.mint(account, 100).unwrap();
+
+    // Withdrawing respects the preservation requirement
+    assert_eq!(
+        contract.withdraw(
+            account,
+            100,
+            Precision::Exact,
+            Preservation::Preserve,
+            Fortitude::Polite,
+        ),
+        Err(Error::Expendability)
+    );
+
+    // The account holder can withdraw their own funds
+    test::set_caller::<ink::env::DefaultEnvironment>(account);
+    let credit = contract
+        .withdraw(
+            account,
+            100,
+            Precision::BestEffort,
+            Preservation::Preserve,
+            Fortitude::Polite,
+        )
+        .unwrap();
+    assert_eq!(credit.peek(), 90);
+    assert_eq!(contract.balance(account), 10);
+    assert_eq!(contract.total_issuance(), 100);
+
+    // Other accounts cannot withdraw on their behalf
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+    assert_eq!(
+        contract.withdraw(
+            account,
+            10,
+            Precision::Exact,
+            Preservation::Expendable,
+            Fortitude::Polite,
+        ),
+        Err(Error::NotAllowed)
+    );
+
+    // Dropping the credit removes it from total issuance
+    test::set_caller::<ink::env::DefaultEnvironment>(account);
+    contract.drop_credit(credit).unwrap();
+    assert_eq!(contract.total_issuance(), 10);
+}
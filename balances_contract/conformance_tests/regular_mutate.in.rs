@@ -306,8 +306,8 @@ fn mutate_transfer_success() {
     let account_0 = accounts.bob;
     let account_1 = accounts.charlie;
     let initial_balance = contract.minimum_balance() + 10;
-    contract.set_balance(account_0, initial_balance);
-    contract.set_balance(account_1, initial_balance);
+    contract.set_balance(account_0, initial_balance).unwrap();
+    contract.set_balance(account_1, initial_balance).unwrap();
 
     // Test: Transfer an amount from account_0 to account_1
     test::set_caller::<ink::env::DefaultEnvironment>(account_0);
@@ -336,8 +336,8 @@ fn mutate_transfer_expendable_all() {
     let account_0 = accounts.bob;
     let account_1 = accounts.charlie;
     let initial_balance = contract.minimum_balance() + 10;
-    contract.set_balance(account_0, initial_balance);
-    contract.set_balance(account_1, initial_balance);
+    contract.set_balance(account_0, initial_balance).unwrap();
+    contract.set_balance(account_1, initial_balance).unwrap();
 
     // Test: Transfer entire balance from account_0 to account_1
     test::set_caller::<ink::env::DefaultEnvironment>(account_0);
@@ -370,8 +370,8 @@ fn mutate_transfer_expendable_dust_no_trap() {
     let account_0 = accounts.bob;
     let account_1 = accounts.charlie;
     let initial_balance = contract.minimum_balance() + 10;
-    contract.set_balance(account_0, initial_balance);
-    contract.set_balance(account_1, initial_balance);
+    contract.set_balance(account_0, initial_balance).unwrap();
+    contract.set_balance(account_1, initial_balance).unwrap();
 
     let initial_total_issuance = contract.total_issuance();
 
@@ -414,8 +414,8 @@ fn mutate_transfer_expendable_dust_with_trap() {
     let account_0 = accounts.bob;
     let account_1 = accounts.charlie;
     let initial_balance = contract.minimum_balance() + 10;
-    contract.set_balance(account_0, initial_balance);
-    contract.set_balance(account_1, initial_balance);
+    contract.set_balance(account_0, initial_balance).unwrap();
+    contract.set_balance(account_1, initial_balance).unwrap();
 
     let initial_total_issuance = contract.total_issuance();
     let initial_dust_trap_balance = contract.total_balance(dust_trap);
@@ -457,8 +457,8 @@ fn mutate_transfer_protect_preserve() {
     let account_0 = accounts.bob;
     let account_1 = accounts.charlie;
     let initial_balance = contract.minimum_balance() + 10;
-    contract.set_balance(account_0, initial_balance);
-    contract.set_balance(account_1, initial_balance);
+    contract.set_balance(account_0, initial_balance).unwrap();
+    contract.set_balance(account_1, initial_balance).unwrap();
 
     // Verify: Transfer Protect entire balance from account_0 to account_1 should Err
     test::set_caller::<ink::env::DefaultEnvironment>(account_0);
@@ -501,7 +501,7 @@ fn mutate_set_balance_mint_success() {
 
     // Test: Increase the account balance with set_balance
     let increase_amount = 5;
-    let new = contract.set_balance(account, initial_balance + increase_amount);
+    let new = contract.set_balance(account, initial_balance + increase_amount).unwrap();
 
     // Verify: set_balance returned the new balance
     let expected_new = initial_balance + increase_amount;
@@ -528,7 +528,7 @@ fn mutate_set_balance_burn_success() {
 
     // Test: Decrease the account balance with set_balance
     let burn_amount = 5;
-    let new = contract.set_balance(account, initial_balance - burn_amount);
+    let new = contract.set_balance(account, initial_balance - burn_amount).unwrap();
 
     // Verify: set_balance returned the new balance
     let expected_new = initial_balance - burn_amount;
@@ -14,9 +14,9 @@ fn unbalanced_write_balance() {
 
     // Test setting the balances of each account by gt the minimum balance succeeds
     let amount = contract.minimum_balance() + 10;
-    contract.set_balance(account_0_ed, amount);
-    contract.set_balance(account_1_gt_ed, amount);
-    contract.set_balance(account_2_empty, amount);
+    contract.set_balance(account_0_ed, amount).unwrap();
+    contract.set_balance(account_1_gt_ed, amount).unwrap();
+    contract.set_balance(account_2_empty, amount).unwrap();
     assert_eq!(contract.balance(account_0_ed), amount);
     assert_eq!(contract.balance(account_1_gt_ed), amount);
     assert_eq!(contract.balance(account_2_empty), amount);
@@ -25,18 +25,18 @@ fn unbalanced_write_balance() {
     // Our set_balance will set to the requested amount regardless
     let amount = contract.minimum_balance() - 1;
     if contract.minimum_balance() == 1 {
-        contract.set_balance(account_0_ed, amount);
-        contract.set_balance(account_1_gt_ed, amount);
-        contract.set_balance(account_2_empty, amount);
+        contract.set_balance(account_0_ed, amount).unwrap();
+        contract.set_balance(account_1_gt_ed, amount).unwrap();
+        contract.set_balance(account_2_empty, amount).unwrap();
         assert_eq!(contract.balance(account_0_ed), amount);
         assert_eq!(contract.balance(account_1_gt_ed), amount);
         assert_eq!(contract.balance(account_2_empty), amount);
     } else if contract.minimum_balance() > 1 {
         // set_balance will reduce to the amount, even if below ED
         // The accounts would be reaped on next operation
-        contract.set_balance(account_0_ed, amount);
-        contract.set_balance(account_1_gt_ed, amount);
-        contract.set_balance(account_2_empty, amount);
+        contract.set_balance(account_0_ed, amount).unwrap();
+        contract.set_balance(account_1_gt_ed, amount).unwrap();
+        contract.set_balance(account_2_empty, amount).unwrap();
         // Our implementation doesn't immediately reap, so balances are set
         assert_eq!(contract.balance(account_0_ed), amount);
         assert_eq!(contract.balance(account_1_gt_ed), amount);
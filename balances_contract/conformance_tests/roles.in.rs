@@ -0,0 +1,137 @@
+/// Test a granted role allows only its own messages until it is revoked
+#[ink::test]
+fn grant_and_revoke_role() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+
+    assert!(!contract.has_role(Role::Minter, accounts.bob));
+    contract.grant_role(Role::Minter, accounts.bob).unwrap();
+    contract
+        .set_minter_quota(accounts.bob, 1_000, 1_000)
+        .unwrap();
+    assert!(contract.has_role(Role::Minter, accounts.bob));
+    assert_eq!(test::recorded_events().count(), 1);
+
+    // Granting a held role again is a no-op
+    contract.grant_role(Role::Minter, accounts.bob).unwrap();
+    assert_eq!(test::recorded_events().count(), 1);
+
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+    contract.mint(accounts.charlie, 100).unwrap();
+    assert_eq!(contract.balance(accounts.charlie), 100);
+    assert_eq!(contract.set_total_issuance(0), Err(Error::NotAllowed));
+    assert_eq!(contract.rescind(10), Err(Error::NotAllowed));
+    assert_eq!(
+        contract.grant_role(Role::Burner, accounts.bob),
+        Err(Error::NotAllowed)
+    );
+
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    contract.revoke_role(Role::Minter, accounts.bob).unwrap();
+    assert!(!contract.has_role(Role::Minter, accounts.bob));
+
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+    assert_eq!(contract.mint(accounts.charlie, 100), Err(Error::NotAllowed));
+    assert_eq!(contract.total_issuance(), 100);
+}
+
+/// Test admins may act in any role and manage roles, but not transfer ownership
+#[ink::test]
+fn admin_acts_in_any_role() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+    contract.grant_role(Role::Admin, accounts.bob).unwrap();
+    contract.set_minter_quota(accounts.bob, 50, 50).unwrap();
+
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+    contract.set_dust_trap(Some(accounts.django)).unwrap();
+    assert_eq!(contract.dust_trap(), Some(accounts.django));
+    contract.mint(accounts.charlie, 50).unwrap();
+    contract
+        .grant_role(Role::Freezer, accounts.charlie)
+        .unwrap();
+    assert!(contract.has_role(Role::Freezer, accounts.charlie));
+    assert_eq!(contract.propose_owner(accounts.bob), Err(Error::NotAllowed));
+
+    // The owner's implicit roles cannot be revoked
+    contract.revoke_role(Role::Admin, accounts.alice).unwrap();
+    assert!(contract.has_role(Role::Admin, accounts.alice));
+}
+
+/// Test an account can renounce a role it holds
+#[ink::test]
+fn renounce_role() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+    contract
+        .grant_role(Role::DustManager, accounts.bob)
+        .unwrap();
+
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+    contract.set_dust_trap(Some(accounts.bob)).unwrap();
+    contract.renounce_role(Role::DustManager).unwrap();
+    assert!(!contract.has_role(Role::DustManager, accounts.bob));
+    assert_eq!(contract.set_dust_trap(None), Err(Error::NotAllowed));
+    assert_eq!(test::recorded_events().count(), 2);
+}
+
+/// Test ownership only moves once the proposed owner accepts it
+#[ink::test]
+fn two_step_ownership_transfer() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+
+    contract.propose_owner(accounts.bob).unwrap();
+    assert_eq!(contract.pending_owner(), Some(accounts.bob));
+    assert_eq!(contract.owner(), accounts.alice);
+
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+    assert_eq!(contract.accept_ownership(), Err(Error::NotAllowed));
+
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+    contract.accept_ownership().unwrap();
+    assert_eq!(contract.owner(), accounts.bob);
+    assert_eq!(contract.pending_owner(), None);
+    assert_eq!(
+        contract.asset_details(NATIVE_ASSET).unwrap().owner,
+        accounts.bob
+    );
+    assert!(contract.is_trusted(accounts.bob));
+    contract.mint(accounts.charlie, 100).unwrap();
+
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    assert!(!contract.has_role(Role::Admin, accounts.alice));
+    assert_eq!(contract.mint(accounts.charlie, 100), Err(Error::NotAllowed));
+    assert_eq!(contract.accept_ownership(), Err(Error::NotAllowed));
+}
+
+/// Test role holders that are not trusted can use the legacy currency deposits
+#[ink::test]
+fn currency_deposits_by_role() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+    contract.grant_role(Role::Minter, accounts.bob).unwrap();
+    contract.set_minter_quota(accounts.bob, 60, 0).unwrap();
+    contract.grant_role(Role::Admin, accounts.django).unwrap();
+    contract.set_minter_quota(accounts.django, 40, 0).unwrap();
+
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+    assert_eq!(contract.deposit_creating(accounts.charlie, 50), Ok(50));
+    assert_eq!(contract.deposit_into_existing(accounts.charlie, 10), Ok(10));
+    assert_eq!(contract.remaining_quota(accounts.bob), 0);
+    assert_eq!(contract.balance(accounts.charlie), 60);
+    assert_eq!(
+        contract.make_free_balance_be(accounts.charlie, 100),
+        Err(Error::NotAllowed)
+    );
+
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+    assert_eq!(contract.make_free_balance_be(accounts.charlie, 100), Ok(100));
+    assert_eq!(contract.balance(accounts.charlie), 100);
+    assert_eq!(contract.total_issuance(), 100);
+}
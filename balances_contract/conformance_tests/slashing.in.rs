@@ -0,0 +1,174 @@
+/// Test that `slash` takes from the free balance, then reserves, then holds.
+#[ink::test]
+fn slash_free_then_reserved_then_held() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+
+    let account = accounts.bob;
+    let reason = *b"staking_";
+    contract.mint(account, 100).unwrap();
+    contract.reserve(account, 20).unwrap();
+    contract.hold(reason, account, 30).unwrap();
+
+    // Only the free balance is slashed
+    let (credit, remaining) = contract.slash(account, 40).unwrap();
+    assert_eq!(credit.peek(), 40);
+    assert_eq!(remaining, 0);
+    assert_eq!(contract.balance(account), 10);
+    assert_eq!(contract.reserved_balance(account), 50);
+
+    // The total issuance only drops once the credit is dropped
+    assert_eq!(contract.total_issuance(), 100);
+    contract.drop_credit(credit).unwrap();
+    assert_eq!(contract.total_issuance(), 60);
+
+    // The rest spills over into the reserve and then the hold
+    let (credit, remaining) = contract.slash(account, 45).unwrap();
+    assert_eq!(credit.peek(), 45);
+    assert_eq!(remaining, 0);
+    assert_eq!(contract.balance(account), 0);
+    assert_eq!(contract.reserved_balance(account), 15);
+    assert_eq!(contract.balance_on_hold(reason, account), 15);
+
+    // Slashing more than the account has reports the remainder
+    let (credit, remaining) = contract.slash(account, 20).unwrap();
+    assert_eq!(credit.peek(), 15);
+    assert_eq!(remaining, 5);
+    assert_eq!(contract.total_balance(account), 0);
+    assert_eq!(contract.total_balance_on_hold(account), 0);
+}
+
+/// Test that `slash_reserved` leaves holds untouched.
+#[ink::test]
+fn slash_reserved_only_reserves() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+
+    let account = accounts.bob;
+    let reason = *b"staking_";
+    contract.mint(account, 100).unwrap();
+    contract.reserve(account, 20).unwrap();
+    contract.hold(reason, account, 30).unwrap();
+
+    let (credit, remaining) = contract.slash_reserved(account, 25).unwrap();
+    assert_eq!(credit.peek(), 20);
+    assert_eq!(remaining, 5);
+    assert_eq!(contract.reserved_balance(account), 30);
+    assert_eq!(contract.balance_on_hold(reason, account), 30);
+    assert_eq!(contract.balance(account), 50);
+}
+
+/// Test that `slash_held` only takes from the given hold.
+#[ink::test]
+fn slash_held_by_reason() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+
+    let account = accounts.bob;
+    let staking = *b"staking_";
+    let governance = *b"govern__";
+    contract.mint(account, 100).unwrap();
+    contract.hold(staking, account, 30).unwrap();
+    contract.hold(governance, account, 20).unwrap();
+
+    let (credit, remaining) = contract.slash_held(staking, account, 40).unwrap();
+    assert_eq!(credit.peek(), 30);
+    assert_eq!(remaining, 10);
+    assert_eq!(contract.balance_on_hold(staking, account), 0);
+    assert_eq!(contract.balance_on_hold(governance, account), 20);
+    assert_eq!(contract.reserved_balance(account), 20);
+    assert_eq!(contract.balance(account), 50);
+
+    // Only the owner can slash
+    test::set_caller::<ink::env::DefaultEnvironment>(account);
+    assert_eq!(contract.slash_held(governance, account, 10), Err(Error::NotAllowed));
+    assert_eq!(contract.slash(account, 10), Err(Error::NotAllowed));
+}
+
+/// Test that slashing an account below the minimum balance reaps it.
+#[ink::test]
+fn slash_reaps_dust() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+
+    let account = accounts.bob;
+    contract.mint(account, 100).unwrap();
+
+    let (credit, remaining) = contract.slash(account, 95).unwrap();
+    assert_eq!(credit.peek(), 95);
+    assert_eq!(remaining, 0);
+    assert_eq!(contract.balance(account), 0);
+
+    // The dust is burned straight away, the slashed funds once the credit is dropped
+    assert_eq!(contract.total_issuance(), 95);
+    contract.drop_credit(credit).unwrap();
+    assert_eq!(contract.total_issuance(), 0);
+}
+
+/// Test `repatriate_reserved` into the free and reserved balance of another account.
+#[ink::test]
+fn repatriate_reserved_success() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+
+    let from = accounts.bob;
+    let to = accounts.charlie;
+    contract.mint(from, 100).unwrap();
+    contract.mint(to, 100).unwrap();
+    contract.reserve(from, 50).unwrap();
+
+    assert_eq!(
+        contract.repatriate_reserved(from, to, 20, BalanceStatus::Free),
+        Ok(0)
+    );
+    assert_eq!(contract.reserved_balance(from), 30);
+    assert_eq!(contract.balance(to), 120);
+
+    assert_eq!(
+        contract.repatriate_reserved(from, to, 40, BalanceStatus::Reserved),
+        Ok(10)
+    );
+    assert_eq!(contract.reserved_balance(from), 0);
+    assert_eq!(contract.reserved_balance(to), 30);
+
+    // Total issuance is unchanged
+    assert_eq!(contract.total_issuance(), 200);
+}
+
+/// Test that `repatriate_reserved` leaves holds untouched and needs a live beneficiary.
+#[ink::test]
+fn repatriate_reserved_restrictions() {
+    let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+    test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+    let mut contract = new_contract(10, 5, None);
+
+    let from = accounts.bob;
+    let to = accounts.charlie;
+    contract.mint(from, 100).unwrap();
+    contract.hold(*b"staking_", from, 50).unwrap();
+
+    assert_eq!(
+        contract.repatriate_reserved(from, to, 20, BalanceStatus::Free),
+        Err(Error::DeadAccount)
+    );
+
+    contract.mint(to, 100).unwrap();
+    assert_eq!(
+        contract.repatriate_reserved(from, to, 20, BalanceStatus::Free),
+        Ok(20)
+    );
+    assert_eq!(contract.balance_on_hold(*b"staking_", from), 50);
+
+    // Repatriating to the same account frees the reserve
+    contract.reserve(from, 10).unwrap();
+    assert_eq!(
+        contract.repatriate_reserved(from, from, 10, BalanceStatus::Free),
+        Ok(0)
+    );
+    assert_eq!(contract.reserved_balance(from), 50);
+}
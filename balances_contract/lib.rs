@@ -20,6 +20,131 @@ mod balances_contract {
         pub frozen: Balance,
     }
 
+    /// Identifier of an asset
+    pub type AssetId = u32;
+
+    /// Storage key of an account of an asset
+    pub type AssetAccount = (AssetId, AccountId);
+
+    /// Asset exposed through the single-asset messages, similar to frame_support's ItemOf
+    pub const NATIVE_ASSET: AssetId = 0;
+
+    /// Per-asset configuration and issuance, similar to pallet_assets
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct AssetDetails {
+        /// Account allowed to mint and administer the asset
+        pub owner: AccountId,
+        /// Minimum balance an account of the asset must keep to exist
+        pub min_balance: Balance,
+        /// Total amount of the asset in existence
+        pub total_issuance: Balance,
+        /// Part of the total issuance that is inactive, never more than the total
+        pub inactive_issuance: Balance,
+    }
+
+    /// Minting allowance of an account other than the owner
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct MinterQuota {
+        /// Amount the account may still mint in total
+        pub allowance: Balance,
+        /// Amount the account may mint within a single window of blocks
+        pub window_cap: Balance,
+        /// Block at which the current window started
+        pub window_start: BlockNumber,
+        /// Amount minted since the current window started
+        pub window_minted: Balance,
+    }
+
+    /// Reference counters of an account, similar to frame_system
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode, Default)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct AccountRefs {
+        /// Number of references that allow the account to exist
+        pub providers: u32,
+        /// Number of references that depend on the account existing
+        pub consumers: u32,
+        /// Number of self-sufficient references that allow the account to exist
+        pub sufficients: u32,
+    }
+
+    /// Kinds of withdrawal a lock applies to, similar to frame_support's WithdrawReasons
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct WithdrawReasons(u8);
+
+    impl WithdrawReasons {
+        /// Paying for a transaction
+        pub const TRANSACTION_PAYMENT: Self = Self(1);
+        /// Transferring funds to another account
+        pub const TRANSFER: Self = Self(2);
+        /// Moving funds into the reserved balance
+        /// Only has an effect with legacy freezes, otherwise reserving ignores every lock
+        pub const RESERVE: Self = Self(4);
+        /// Paying a fee other than for a transaction
+        pub const FEE: Self = Self(8);
+        /// Paying a tip
+        pub const TIP: Self = Self(16);
+
+        /// No withdrawal kinds
+        pub fn empty() -> Self {
+            Self(0)
+        }
+
+        /// Every withdrawal kind
+        pub fn all() -> Self {
+            Self(31)
+        }
+
+        /// Every withdrawal kind except the given ones
+        pub fn except(reasons: Self) -> Self {
+            Self(Self::all().0 & !reasons.0)
+        }
+
+        /// Build from raw bits, ignoring unknown ones
+        pub fn from_bits(bits: u8) -> Self {
+            Self(bits & Self::all().0)
+        }
+
+        /// Raw bit representation
+        pub fn bits(&self) -> u8 {
+            self.0
+        }
+
+        /// Check if no withdrawal kind is included
+        pub fn is_empty(&self) -> bool {
+            self.0 == 0
+        }
+
+        /// Check if all of the given kinds are included
+        pub fn contains(&self, reasons: Self) -> bool {
+            self.0 & reasons.0 == reasons.0
+        }
+
+        /// Check if any of the given kinds is included
+        pub fn intersects(&self, reasons: Self) -> bool {
+            self.0 & reasons.0 != 0
+        }
+    }
+
+    impl core::ops::BitOr for WithdrawReasons {
+        type Output = Self;
+
+        fn bitor(self, rhs: Self) -> Self {
+            Self(self.0 | rhs.0)
+        }
+    }
+
     /// Balance lock structure
     #[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
     #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
@@ -28,34 +153,179 @@ mod balances_contract {
         pub id: [u8; 8],
         /// Amount locked
         pub amount: Balance,
+        /// Kinds of withdrawal the lock applies to
+        pub reasons: WithdrawReasons,
+    }
+
+    /// Identifier of the reason for which funds are placed on hold
+    pub type HoldReason = [u8; 8];
+
+    /// Hold reason under which a treasury receives dust
+    pub const DUST_HOLD_REASON: HoldReason = *b"dustheld";
+
+    /// Identifier of the reason for which funds are frozen
+    pub type FreezeReason = [u8; 8];
+
+    /// Identifier of a named reserve
+    pub type ReserveIdentifier = [u8; 8];
+
+    /// Amount reserved under a given identifier
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct ReserveData {
+        /// Identifier of the reserve
+        pub id: ReserveIdentifier,
+        /// Amount reserved
+        pub amount: Balance,
     }
 
+    /// Amount held or frozen under a given identifier
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct IdAmount {
+        /// Identifier of the hold or freeze
+        pub id: [u8; 8],
+        /// Amount held or frozen
+        pub amount: Balance,
+    }
+
+    /// Identifier of an imbalance issued by the contract
+    pub type ImbalanceId = u64;
+
     /// Represents a positive imbalance (credit) - tokens that exist but aren't yet assigned
+    /// The handle refers to an outstanding credit recorded by the contract and is only
+    /// accepted back from the caller it was issued to
     #[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
     #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
     pub struct CreditImbalance {
+        id: ImbalanceId,
         amount: Balance,
     }
 
     /// Represents a negative imbalance (debt) - tokens that are owed but don't exist yet
+    /// The handle refers to an outstanding debt recorded by the contract and is only
+    /// accepted back from the caller it was issued to
     #[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
     #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
     pub struct DebtImbalance {
+        id: ImbalanceId,
         amount: Balance,
     }
 
     impl CreditImbalance {
+        /// An empty credit, which needs no backing record
+        pub fn zero() -> Self {
+            Self { id: 0, amount: 0 }
+        }
+
+        pub fn id(&self) -> ImbalanceId {
+            self.id
+        }
+
         pub fn peek(&self) -> Balance {
             self.amount
         }
     }
 
     impl DebtImbalance {
+        /// An empty debt, which needs no backing record
+        pub fn zero() -> Self {
+            Self { id: 0, amount: 0 }
+        }
+
+        pub fn id(&self) -> ImbalanceId {
+            self.id
+        }
+
         pub fn peek(&self) -> Balance {
             self.amount
         }
     }
 
+    /// Outstanding imbalance recorded by the contract
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct ImbalanceRecord {
+        /// Caller the imbalance was issued to, the only one allowed to use it
+        pub holder: AccountId,
+        /// Outstanding amount
+        pub amount: Balance,
+        /// Part of the amount issued as new tokens, charged against the holder's minting
+        /// quota when the credit was created and released if it is cancelled
+        pub minted: Balance,
+    }
+
+    /// Result of offsetting a credit against a debt
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum SameOrOther {
+        /// The credit and debt cancelled each other out exactly
+        None,
+        /// The credit was larger, the excess remains as credit
+        Same(CreditImbalance),
+        /// The debt was larger, the excess remains as debt
+        Other(DebtImbalance),
+    }
+
+    /// Administrative role that can be granted to an account
+    /// The owner holds every role implicitly, and admins may act in any role
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum Role {
+        /// Manages roles, assets, trusted callers and raw account data
+        Admin,
+        /// Creates new native tokens
+        Minter,
+        /// Destroys native tokens by rescinding or slashing
+        Burner,
+        /// Blocks accounts from being created
+        Freezer,
+        /// Adjusts total and active issuance
+        IssuanceManager,
+        /// Chooses where dust goes
+        DustManager,
+    }
+
+    /// What happens to dust left behind when an account is reaped
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub enum DustPolicy {
+        /// Dust is destroyed, reducing total issuance
+        Burn,
+        /// Dust is moved to the free balance of a trap account
+        Trap(AccountId),
+        /// Dust is placed on hold on a treasury account under `DUST_HOLD_REASON`
+        Treasury(AccountId),
+        /// Dust accumulates in a per-asset pot that can be swept later
+        Pot,
+    }
+
+    /// Balance into which repatriated funds are moved
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum BalanceStatus {
+        /// Funds are moved to the free balance
+        Free,
+        /// Funds are moved to the reserved balance
+        Reserved,
+    }
+
+    /// Where transferred held funds end up on the destination account
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum Restriction {
+        /// Funds are credited to the free balance
+        Free,
+        /// Funds are kept on hold for the same reason
+        OnHold,
+    }
+
     /// Preservation mode for transfers and burns
     #[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
     #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
@@ -64,10 +334,20 @@ mod balances_contract {
         Expendable,
         /// Account must stay above existential deposit
         Preserve,
-        /// Similar to Preserve
+        /// Account must stay above existential deposit only when its balance is the sole provider
         Protect,
     }
 
+    /// Whether a legacy withdrawal may kill the account, similar to frame_support's ExistenceRequirement
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum ExistenceRequirement {
+        /// Account must stay at or above existential deposit
+        KeepAlive,
+        /// Account may be reaped
+        AllowDeath,
+    }
+
     /// Precision mode for operations
     #[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
     #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
@@ -112,6 +392,8 @@ mod balances_contract {
         CannotCreate,
         /// Unknown error
         UnknownAsset,
+        /// Minting would take total issuance above the maximum supply
+        ExceedsMaxSupply,
     }
 
     /// Result of checking if withdrawal is possible
@@ -132,11 +414,14 @@ mod balances_contract {
         UnknownAsset,
         /// Funds are locked
         Frozen,
+        /// Withdrawal would reduce total issuance below zero
+        Underflow,
     }
 
     /// Events emitted by the contract
     #[ink(event)]
     pub struct Transfer {
+        asset: AssetId,
         #[ink(topic)]
         from: Option<AccountId>,
         #[ink(topic)]
@@ -146,15 +431,35 @@ mod balances_contract {
 
     #[ink(event)]
     pub struct Endowed {
+        asset: AssetId,
         #[ink(topic)]
         account: AccountId,
         free_balance: Balance,
     }
 
+    #[ink(event)]
+    pub struct Killed {
+        asset: AssetId,
+        #[ink(topic)]
+        account: AccountId,
+    }
+
     #[ink(event)]
     pub struct DustLost {
+        asset: AssetId,
+        #[ink(topic)]
+        account: AccountId,
+        amount: Balance,
+    }
+
+    #[ink(event)]
+    pub struct DustTrapped {
+        asset: AssetId,
         #[ink(topic)]
         account: AccountId,
+        /// Account the dust went to, or `None` for the dust pot
+        #[ink(topic)]
+        destination: Option<AccountId>,
         amount: Balance,
     }
 
@@ -172,15 +477,87 @@ mod balances_contract {
         amount: Balance,
     }
 
+    #[ink(event)]
+    pub struct Held {
+        asset: AssetId,
+        #[ink(topic)]
+        reason: HoldReason,
+        #[ink(topic)]
+        who: AccountId,
+        amount: Balance,
+    }
+
+    #[ink(event)]
+    pub struct Released {
+        asset: AssetId,
+        #[ink(topic)]
+        reason: HoldReason,
+        #[ink(topic)]
+        who: AccountId,
+        amount: Balance,
+    }
+
     #[ink(event)]
     pub struct Locked {
         #[ink(topic)]
         who: AccountId,
+        id: [u8; 8],
         amount: Balance,
     }
 
     #[ink(event)]
     pub struct Unlocked {
+        #[ink(topic)]
+        who: AccountId,
+        id: [u8; 8],
+        amount: Balance,
+    }
+
+    #[ink(event)]
+    pub struct Frozen {
+        asset: AssetId,
+        #[ink(topic)]
+        who: AccountId,
+        amount: Balance,
+    }
+
+    #[ink(event)]
+    pub struct Thawed {
+        asset: AssetId,
+        #[ink(topic)]
+        who: AccountId,
+        amount: Balance,
+    }
+
+    #[ink(event)]
+    pub struct Deactivated {
+        amount: Balance,
+    }
+
+    #[ink(event)]
+    pub struct Reactivated {
+        amount: Balance,
+    }
+
+    #[ink(event)]
+    pub struct Issued {
+        amount: Balance,
+    }
+
+    #[ink(event)]
+    pub struct Rescinded {
+        amount: Balance,
+    }
+
+    #[ink(event)]
+    pub struct Deposit {
+        #[ink(topic)]
+        who: AccountId,
+        amount: Balance,
+    }
+
+    #[ink(event)]
+    pub struct Withdraw {
         #[ink(topic)]
         who: AccountId,
         amount: Balance,
@@ -188,6 +565,7 @@ mod balances_contract {
 
     #[ink(event)]
     pub struct Burned {
+        asset: AssetId,
         #[ink(topic)]
         who: AccountId,
         amount: Balance,
@@ -195,6 +573,7 @@ mod balances_contract {
 
     #[ink(event)]
     pub struct Restored {
+        asset: AssetId,
         #[ink(topic)]
         who: AccountId,
         amount: Balance,
@@ -202,11 +581,96 @@ mod balances_contract {
 
     #[ink(event)]
     pub struct Shelved {
+        asset: AssetId,
+        #[ink(topic)]
+        who: AccountId,
+        amount: Balance,
+    }
+
+    #[ink(event)]
+    pub struct TransferOnHold {
+        asset: AssetId,
+        #[ink(topic)]
+        reason: HoldReason,
+        #[ink(topic)]
+        source: AccountId,
+        #[ink(topic)]
+        dest: AccountId,
+        amount: Balance,
+    }
+
+    #[ink(event)]
+    pub struct TransferAndHold {
+        asset: AssetId,
+        #[ink(topic)]
+        reason: HoldReason,
+        #[ink(topic)]
+        source: AccountId,
+        #[ink(topic)]
+        dest: AccountId,
+        amount: Balance,
+    }
+
+    #[ink(event)]
+    pub struct AssetCreated {
+        #[ink(topic)]
+        asset: AssetId,
+        #[ink(topic)]
+        owner: AccountId,
+        min_balance: Balance,
+    }
+
+    #[ink(event)]
+    pub struct RoleGranted {
+        #[ink(topic)]
+        role: Role,
+        #[ink(topic)]
+        account: AccountId,
+        sender: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct RoleRevoked {
+        #[ink(topic)]
+        role: Role,
+        #[ink(topic)]
+        account: AccountId,
+        sender: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct OwnershipProposed {
+        #[ink(topic)]
+        owner: AccountId,
+        #[ink(topic)]
+        proposed: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct OwnershipTransferred {
+        #[ink(topic)]
+        previous: AccountId,
+        #[ink(topic)]
+        owner: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct Slashed {
         #[ink(topic)]
         who: AccountId,
         amount: Balance,
     }
 
+    #[ink(event)]
+    pub struct ReserveRepatriated {
+        #[ink(topic)]
+        from: AccountId,
+        #[ink(topic)]
+        to: AccountId,
+        amount: Balance,
+        destination_status: BalanceStatus,
+    }
+
     /// Custom errors
     #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
     #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
@@ -221,8 +685,36 @@ mod balances_contract {
         Expendability,
         /// Arithmetic overflow
         Overflow,
+        /// Arithmetic underflow
+        Underflow,
+        /// Account has no providers, so it cannot be depended on
+        NoProviders,
+        /// Account still has consumers, so its last provider cannot be removed
+        ConsumerRemaining,
+        /// Account cannot be created
+        CannotCreate,
+        /// Asset is unknown
+        UnknownAsset,
+        /// Asset already exists
+        AssetExists,
+        /// Minimum balance of a new asset must be positive
+        MinBalanceZero,
+        /// Caller's minting allowance or window cap would be exceeded
+        MinterQuotaExceeded,
+        /// Total issuance would exceed the maximum supply
+        MaxSupplyExceeded,
+        /// Beneficiary account does not exist
+        DeadAccount,
         /// Too many locks on account
         TooManyLocks,
+        /// Too many distinct holds on account
+        TooManyHolds,
+        /// Too many distinct freezes on account
+        TooManyFreezes,
+        /// Too many named reserves on account
+        TooManyReserves,
+        /// Imbalance is unknown, already used or issued to another caller
+        InvalidImbalance,
         /// Operation not allowed
         NotAllowed,
     }
@@ -232,22 +724,60 @@ mod balances_contract {
     /// Storage structure
     #[ink(storage)]
     pub struct BalancesContract {
-        /// Total token supply
-        total_issuance: Balance,
-        /// Active issuance (total - inactive/deactivated)
-        active_issuance: Balance,
-        /// Mapping from account to their balance data
-        accounts: Mapping<AccountId, AccountData>,
-        /// Locks on accounts
+        /// Configuration and issuance of each asset, including the native one
+        assets: Mapping<AssetId, AssetDetails>,
+        /// Mapping from asset and account to their balance data
+        accounts: Mapping<AssetAccount, AccountData>,
+        /// Locks on native accounts
         locks: Mapping<AccountId, Vec<BalanceLock>>,
-        /// Existential deposit - minimum balance to keep account alive
-        existential_deposit: Balance,
+        /// Holds on accounts, one entry per hold reason
+        holds: Mapping<AssetAccount, Vec<IdAmount>>,
+        /// Freezes on accounts, one entry per freeze reason
+        freezes: Mapping<AssetAccount, Vec<IdAmount>>,
+        /// Named reserves on accounts, one entry per reserve identifier
+        reserves: Mapping<AccountId, Vec<ReserveData>>,
+        /// Accounts that may not be created
+        blocked: Mapping<AccountId, ()>,
+        /// Reference counters added through the refcount messages
+        /// The references held by the contract itself are derived from the account data
+        refs: Mapping<AccountId, AccountRefs>,
         /// Maximum number of locks per account
         max_locks: u32,
-        /// Contract owner
+        /// Maximum number of distinct holds per account
+        max_holds: u32,
+        /// Maximum number of distinct freezes per account
+        max_freezes: u32,
+        /// Maximum number of named reserves per account
+        max_reserves: u32,
+        /// Contract owner, the root origin
         owner: AccountId,
-        /// Optional dust trap account
-        dust_trap: Option<AccountId>,
+        /// Account proposed as the next owner, which still has to accept
+        pending_owner: Option<AccountId>,
+        /// Roles granted to accounts besides the owner
+        roles: Mapping<(Role, AccountId), ()>,
+        /// Callers trusted to move funds on behalf of any account
+        trusted: Mapping<AccountId, ()>,
+        /// Minting quotas of accounts other than the owner
+        minter_quotas: Mapping<AccountId, MinterQuota>,
+        /// Length in blocks of a quota window, zero disables the window caps
+        quota_window: BlockNumber,
+        /// Where dust of reaped accounts goes
+        dust_policy: DustPolicy,
+        /// Dust collected under the pot policy, per asset
+        dust_pots: Mapping<AssetId, Balance>,
+        /// Dust returned by `write_balance` that its caller has not handled yet
+        raw_dust: Mapping<AccountId, Balance>,
+        /// Optional cap on the total issuance of the native asset
+        max_supply: Option<Balance>,
+        /// Whether freezes apply to the free balance only, as in older pallet versions
+        /// By default holds count towards satisfying freezes
+        legacy_freezes: bool,
+        /// Outstanding credit imbalances
+        credits: Mapping<ImbalanceId, ImbalanceRecord>,
+        /// Outstanding debt imbalances
+        debts: Mapping<ImbalanceId, ImbalanceRecord>,
+        /// Identifier assigned to the next recorded imbalance
+        next_imbalance_id: ImbalanceId,
     }
 
     impl BalancesContract {
@@ -255,15 +785,45 @@ mod balances_contract {
         #[ink(constructor)]
         pub fn new(existential_deposit: Balance, max_locks: u32) -> Self {
             let caller = Self::env().caller();
+            let mut assets = Mapping::default();
+            assets.insert(
+                NATIVE_ASSET,
+                &AssetDetails {
+                    owner: caller,
+                    min_balance: existential_deposit,
+                    total_issuance: 0,
+                    inactive_issuance: 0,
+                },
+            );
             Self {
-                total_issuance: 0,
-                active_issuance: 0,
+                assets,
                 accounts: Mapping::default(),
                 locks: Mapping::default(),
-                existential_deposit,
+                holds: Mapping::default(),
+                freezes: Mapping::default(),
+                reserves: Mapping::default(),
+                blocked: Mapping::default(),
+                refs: Mapping::default(),
                 max_locks,
+                // Holds, freezes and named reserves share the per-account bound configured for locks
+                max_holds: max_locks,
+                max_freezes: max_locks,
+                max_reserves: max_locks,
                 owner: caller,
-                dust_trap: None,
+                pending_owner: None,
+                roles: Mapping::default(),
+                trusted: Mapping::default(),
+                minter_quotas: Mapping::default(),
+                quota_window: 0,
+                dust_policy: DustPolicy::Burn,
+                dust_pots: Mapping::default(),
+                raw_dust: Mapping::default(),
+                max_supply: None,
+                legacy_freezes: false,
+                credits: Mapping::default(),
+                debts: Mapping::default(),
+                // Identifier 0 is reserved for zero imbalances
+                next_imbalance_id: 1,
             }
         }
 
@@ -274,53 +834,279 @@ mod balances_contract {
             max_locks: u32,
             dust_trap: AccountId,
         ) -> Self {
-            let caller = Self::env().caller();
-            Self {
-                total_issuance: 0,
-                active_issuance: 0,
-                accounts: Mapping::default(),
-                locks: Mapping::default(),
-                existential_deposit,
-                max_locks,
-                owner: caller,
-                dust_trap: Some(dust_trap),
-            }
+            Self::new_with_dust_policy(existential_deposit, max_locks, DustPolicy::Trap(dust_trap))
         }
 
-        /// Default constructor
+        /// Constructor with a dust policy
         #[ink(constructor)]
-        pub fn default() -> Self {
-            Self::new(1, 10)
+        pub fn new_with_dust_policy(
+            existential_deposit: Balance,
+            max_locks: u32,
+            dust_policy: DustPolicy,
+        ) -> Self {
+            let mut contract = Self::new(existential_deposit, max_locks);
+            contract.dust_policy = dust_policy;
+            contract
         }
 
-        /// Get the total token supply
-        #[ink(message)]
-        pub fn total_issuance(&self) -> Balance {
-            self.total_issuance
+        /// Constructor with a cap on the total issuance
+        #[ink(constructor)]
+        pub fn new_with_max_supply(
+            existential_deposit: Balance,
+            max_locks: u32,
+            max_supply: Balance,
+        ) -> Self {
+            let mut contract = Self::new(existential_deposit, max_locks);
+            contract.max_supply = Some(max_supply);
+            contract
         }
 
-        /// Get the active issuance (total - deactivated)
-        #[ink(message)]
-        pub fn active_issuance(&self) -> Balance {
-            self.active_issuance
+        /// Constructor using the legacy balance model, where freezes apply to the free
+        /// balance only and held funds do not count towards them
+        #[ink(constructor)]
+        pub fn new_with_legacy_freezes(existential_deposit: Balance, max_locks: u32) -> Self {
+            let mut contract = Self::new(existential_deposit, max_locks);
+            contract.legacy_freezes = true;
+            contract
+        }
+
+        /// Default constructor
+        #[ink(constructor)]
+        pub fn default() -> Self {
+            Self::new(1, 10)
+        }
+
+        /// Create a new asset with its own minimum balance and owner (only contract owner)
+        /// Origin: root or the `Admin` role
+        #[ink(message)]
+        pub fn create_asset(
+            &mut self,
+            asset: AssetId,
+            min_balance: Balance,
+            owner: AccountId,
+        ) -> Result<()> {
+            self.ensure_role(Role::Admin)?;
+            if self.assets.contains(asset) {
+                return Err(Error::AssetExists);
+            }
+            if min_balance == 0 {
+                return Err(Error::MinBalanceZero);
+            }
+
+            self.assets.insert(
+                asset,
+                &AssetDetails {
+                    owner,
+                    min_balance,
+                    total_issuance: 0,
+                    inactive_issuance: 0,
+                },
+            );
+
+            self.env().emit_event(AssetCreated {
+                asset,
+                owner,
+                min_balance,
+            });
+
+            Ok(())
+        }
+
+        /// Get the configuration and issuance of an asset
+        #[ink(message)]
+        pub fn asset_details(&self, asset: AssetId) -> Option<AssetDetails> {
+            self.assets.get(asset)
+        }
+
+        /// Get the total token supply
+        #[ink(message)]
+        pub fn total_issuance(&self) -> Balance {
+            self.asset_total_issuance(NATIVE_ASSET)
+        }
+
+        /// Get the total supply of an asset
+        #[ink(message)]
+        pub fn asset_total_issuance(&self, asset: AssetId) -> Balance {
+            self.details(asset).total_issuance
+        }
+
+        /// Get the active issuance (total - deactivated)
+        #[ink(message)]
+        pub fn active_issuance(&self) -> Balance {
+            self.asset_active_issuance(NATIVE_ASSET)
+        }
+
+        /// Get the active issuance of an asset
+        #[ink(message)]
+        pub fn asset_active_issuance(&self, asset: AssetId) -> Balance {
+            let details = self.details(asset);
+            details
+                .total_issuance
+                .saturating_sub(details.inactive_issuance)
+        }
+
+        /// Get the deactivated part of the total issuance
+        #[ink(message)]
+        pub fn inactive_issuance(&self) -> Balance {
+            self.asset_inactive_issuance(NATIVE_ASSET)
+        }
+
+        /// Get the deactivated part of the total issuance of an asset
+        #[ink(message)]
+        pub fn asset_inactive_issuance(&self, asset: AssetId) -> Balance {
+            self.details(asset).inactive_issuance
         }
 
         /// Get the existential deposit
         #[ink(message)]
         pub fn existential_deposit(&self) -> Balance {
-            self.existential_deposit
+            self.minimum_balance()
         }
 
         /// Get the minimum balance (same as existential deposit)
         #[ink(message)]
         pub fn minimum_balance(&self) -> Balance {
-            self.existential_deposit
+            self.asset_minimum_balance(NATIVE_ASSET)
+        }
+
+        /// Get the minimum balance of an asset
+        #[ink(message)]
+        pub fn asset_minimum_balance(&self, asset: AssetId) -> Balance {
+            self.details(asset).min_balance
         }
 
         /// Get account data for an account
         #[ink(message)]
         pub fn account(&self, who: AccountId) -> AccountData {
-            self.accounts.get(who).unwrap_or_default()
+            self.asset_account(NATIVE_ASSET, who)
+        }
+
+        /// Get account data for an account of an asset
+        #[ink(message)]
+        pub fn asset_account(&self, asset: AssetId, who: AccountId) -> AccountData {
+            self.accounts.get((asset, who)).unwrap_or_default()
+        }
+
+        /// Get the free balance of an account of an asset
+        #[ink(message)]
+        pub fn asset_balance(&self, asset: AssetId, who: AccountId) -> Balance {
+            self.asset_account(asset, who).free
+        }
+
+        /// Get the total balance (free + reserved) of an account of an asset
+        #[ink(message)]
+        pub fn asset_total_balance(&self, asset: AssetId, who: AccountId) -> Balance {
+            let account = self.asset_account(asset, who);
+            account.free.saturating_add(account.reserved)
+        }
+
+        /// Get the details of an asset
+        /// Unknown assets have no issuance and are administered by the contract owner
+        fn details(&self, asset: AssetId) -> AssetDetails {
+            self.assets.get(asset).unwrap_or(AssetDetails {
+                owner: self.owner,
+                min_balance: 0,
+                total_issuance: 0,
+                inactive_issuance: 0,
+            })
+        }
+
+        /// Fail unless the caller is the root origin, i.e. the contract owner
+        fn ensure_root(&self) -> Result<()> {
+            if self.env().caller() != self.owner {
+                return Err(Error::NotAllowed);
+            }
+            Ok(())
+        }
+
+        /// Fail unless the caller holds the role, is an admin or is the owner
+        fn ensure_role(&self, role: Role) -> Result<()> {
+            let caller = self.env().caller();
+            if !self.has_role(role, caller) && !self.has_role(Role::Admin, caller) {
+                return Err(Error::NotAllowed);
+            }
+            Ok(())
+        }
+
+        /// Fail unless the caller is the owner or a registered trusted caller
+        fn ensure_trusted(&self) -> Result<()> {
+            if !self.is_trusted(self.env().caller()) {
+                return Err(Error::NotAllowed);
+            }
+            Ok(())
+        }
+
+        /// Fail unless the call is signed by `who` or made by a trusted caller on its behalf
+        fn ensure_signed_by(&self, who: AccountId) -> Result<()> {
+            let caller = self.env().caller();
+            if caller != who && !self.is_trusted(caller) {
+                return Err(Error::NotAllowed);
+            }
+            Ok(())
+        }
+
+        /// Fail unless the asset has been created
+        fn ensure_asset(&self, asset: AssetId) -> Result<()> {
+            if !self.assets.contains(asset) {
+                return Err(Error::UnknownAsset);
+            }
+            Ok(())
+        }
+
+        /// Highest total issuance an asset may reach
+        /// Only the native asset can be capped below the maximum balance
+        fn max_issuance(&self, asset: AssetId) -> Balance {
+            match self.max_supply {
+                Some(max_supply) if asset == NATIVE_ASSET => max_supply,
+                _ => Balance::MAX,
+            }
+        }
+
+        /// Amount that can still be minted before reaching the maximum issuance of an asset
+        fn issuance_headroom(&self, asset: AssetId) -> Balance {
+            self.max_issuance(asset)
+                .saturating_sub(self.asset_total_issuance(asset))
+        }
+
+        /// Ensure minting the amount neither overflows nor exceeds the maximum supply
+        fn ensure_can_mint(&self, asset: AssetId, amount: Balance) -> Result<()> {
+            let new_total = self
+                .asset_total_issuance(asset)
+                .checked_add(amount)
+                .ok_or(Error::Overflow)?;
+            if new_total > self.max_issuance(asset) {
+                return Err(Error::MaxSupplyExceeded);
+            }
+            Ok(())
+        }
+
+        /// Add minted funds to the total issuance of an asset, saturating
+        /// Minted funds are active, so only the active issuance grows with it
+        fn mint_issuance(&mut self, asset: AssetId, amount: Balance) {
+            let mut details = self.details(asset);
+            details.total_issuance = details.total_issuance.saturating_add(amount);
+            self.assets.insert(asset, &details);
+        }
+
+        /// Remove burned funds from the total issuance of an asset, saturating
+        fn burn_issuance(&mut self, asset: AssetId, amount: Balance) {
+            let mut details = self.details(asset);
+            details.total_issuance = details.total_issuance.saturating_sub(amount);
+            self.store_issuance(asset, details);
+        }
+
+        /// Store issuance details, reactivating whatever inactive issuance exceeds the total
+        /// This keeps active issuance equal to total minus inactive issuance
+        fn store_issuance(&mut self, asset: AssetId, mut details: AssetDetails) {
+            let excess = details
+                .inactive_issuance
+                .saturating_sub(details.total_issuance);
+            details.inactive_issuance = details.inactive_issuance.saturating_sub(excess);
+            self.assets.insert(asset, &details);
+
+            if excess > 0 {
+                self.env().emit_event(Reactivated { amount: excess });
+            }
         }
 
         /// Get the free balance of an account
@@ -344,812 +1130,3727 @@ mod balances_contract {
         /// Get the total balance (free + reserved) of an account
         #[ink(message)]
         pub fn total_balance(&self, who: AccountId) -> Balance {
-            let account = self.account(who);
-            account.free.saturating_add(account.reserved)
+            self.asset_total_balance(NATIVE_ASSET, who)
         }
 
         /// Get usable balance (free - frozen)
         #[ink(message)]
         pub fn usable_balance(&self, who: AccountId) -> Balance {
+            self.liquid_balance(&self.account(who), Fortitude::Polite)
+        }
+
+        /// Get the reference counters of an account
+        /// A balance of the account provides a reference, holds, locks and freezes consume one
+        #[ink(message)]
+        pub fn account_refs(&self, who: AccountId) -> AccountRefs {
+            let mut refs = self.refs.get(who).unwrap_or_default();
             let account = self.account(who);
-            account.free.saturating_sub(account.frozen)
+
+            if account.free.saturating_add(account.reserved) > 0 {
+                refs.providers = refs.providers.saturating_add(1);
+            }
+            if account.reserved > 0
+                || self.locks.contains(who)
+                || self.freezes.contains((NATIVE_ASSET, who))
+            {
+                refs.consumers = refs.consumers.saturating_add(1);
+            }
+
+            refs
+        }
+
+        /// Get the number of providers of an account
+        #[ink(message)]
+        pub fn providers(&self, who: AccountId) -> u32 {
+            self.account_refs(who).providers
+        }
+
+        /// Get the number of consumers of an account
+        #[ink(message)]
+        pub fn consumers(&self, who: AccountId) -> u32 {
+            self.account_refs(who).consumers
+        }
+
+        /// Get the number of sufficients of an account
+        #[ink(message)]
+        pub fn sufficients(&self, who: AccountId) -> u32 {
+            self.account_refs(who).sufficients
+        }
+
+        /// Check if a provider could be removed without leaving consumers behind
+        #[ink(message)]
+        pub fn can_dec_provider(&self, who: AccountId) -> bool {
+            let refs = self.account_refs(who);
+            refs.consumers == 0 || refs.providers > 1
+        }
+
+        /// Add a provider reference to an account
+        /// Origin: root or the `Admin` role
+        #[ink(message)]
+        pub fn inc_providers(&mut self, who: AccountId) -> Result<()> {
+            self.ensure_role(Role::Admin)?;
+
+            let mut refs = self.refs.get(who).unwrap_or_default();
+            refs.providers = refs.providers.checked_add(1).ok_or(Error::Overflow)?;
+            self.refs.insert(who, &refs);
+            Ok(())
+        }
+
+        /// Remove a provider reference from an account
+        /// The last provider cannot be removed while the account has consumers
+        /// Origin: root or the `Admin` role
+        #[ink(message)]
+        pub fn dec_providers(&mut self, who: AccountId) -> Result<()> {
+            self.ensure_role(Role::Admin)?;
+
+            let mut refs = self.refs.get(who).unwrap_or_default();
+            if refs.providers == 0 {
+                return Err(Error::Underflow);
+            }
+            if !self.can_dec_provider(who) {
+                return Err(Error::ConsumerRemaining);
+            }
+
+            refs.providers = refs.providers.saturating_sub(1);
+            self.update_refs(who, refs);
+            Ok(())
+        }
+
+        /// Add a consumer reference to an account, which must have a provider
+        /// Origin: root or the `Admin` role
+        #[ink(message)]
+        pub fn inc_consumers(&mut self, who: AccountId) -> Result<()> {
+            self.ensure_role(Role::Admin)?;
+            if self.providers(who) == 0 {
+                return Err(Error::NoProviders);
+            }
+
+            let mut refs = self.refs.get(who).unwrap_or_default();
+            refs.consumers = refs.consumers.checked_add(1).ok_or(Error::Overflow)?;
+            self.refs.insert(who, &refs);
+            Ok(())
+        }
+
+        /// Remove a consumer reference from an account
+        /// Origin: root or the `Admin` role
+        #[ink(message)]
+        pub fn dec_consumers(&mut self, who: AccountId) -> Result<()> {
+            self.ensure_role(Role::Admin)?;
+
+            let mut refs = self.refs.get(who).unwrap_or_default();
+            refs.consumers = refs.consumers.saturating_sub(1);
+            self.update_refs(who, refs);
+            Ok(())
+        }
+
+        /// Add a self-sufficient reference to an account
+        /// Origin: root or the `Admin` role
+        #[ink(message)]
+        pub fn inc_sufficients(&mut self, who: AccountId) -> Result<()> {
+            self.ensure_role(Role::Admin)?;
+
+            let mut refs = self.refs.get(who).unwrap_or_default();
+            refs.sufficients = refs.sufficients.checked_add(1).ok_or(Error::Overflow)?;
+            self.refs.insert(who, &refs);
+            Ok(())
+        }
+
+        /// Remove a self-sufficient reference from an account
+        /// Origin: root or the `Admin` role
+        #[ink(message)]
+        pub fn dec_sufficients(&mut self, who: AccountId) -> Result<()> {
+            self.ensure_role(Role::Admin)?;
+
+            let mut refs = self.refs.get(who).unwrap_or_default();
+            refs.sufficients = refs.sufficients.saturating_sub(1);
+            self.update_refs(who, refs);
+            Ok(())
+        }
+
+        /// Store reference counters, removing the entry once all of them are zero
+        fn update_refs(&mut self, who: AccountId, refs: AccountRefs) {
+            if refs == AccountRefs::default() {
+                self.refs.remove(who);
+            } else {
+                self.refs.insert(who, &refs);
+            }
+        }
+
+        /// Check if an account must keep the existential deposit under a preservation mode
+        /// Only the native balance provides references, other assets just follow the mode
+        fn must_keep_alive(
+            &self,
+            asset: AssetId,
+            who: AccountId,
+            preservation: Preservation,
+        ) -> bool {
+            if asset != NATIVE_ASSET {
+                return preservation != Preservation::Expendable;
+            }
+            match preservation {
+                Preservation::Preserve => true,
+                // Keep the account alive if the balance is its only provider
+                Preservation::Protect => self.providers(who) == 1,
+                // The account may die, unless that would leave consumers without a provider
+                Preservation::Expendable => !self.can_dec_provider(who),
+            }
         }
 
         /// Create a pair of matching credit and debt imbalances
         /// This is useful for operations that need to temporarily adjust balances
+        /// Total issuance is unaffected since the credit and debt cancel each other out
+        /// Origin: root or the `IssuanceManager` role
         #[ink(message)]
-        pub fn pair(&self, amount: Balance) -> Result<(CreditImbalance, DebtImbalance)> {
+        pub fn pair(&mut self, amount: Balance) -> Result<(CreditImbalance, DebtImbalance)> {
+            self.ensure_role(Role::IssuanceManager)?;
+
             if amount == 0 {
-                return Ok((CreditImbalance { amount: 0 }, DebtImbalance { amount: 0 }));
+                return Ok((CreditImbalance::zero(), DebtImbalance::zero()));
             }
 
-            // Check if creating this credit would overflow total_issuance
-            self.total_issuance
-                .checked_add(amount)
-                .ok_or(Error::Overflow)?;
+            // Check if creating this credit would overflow total_issuance or the maximum supply
+            self.ensure_can_mint(NATIVE_ASSET, amount)?;
+            self.ensure_quota(amount)?;
+            self.consume_quota(amount);
 
-            Ok((CreditImbalance { amount }, DebtImbalance { amount }))
+            Ok((self.new_credit(amount, amount), self.new_debt(amount)))
         }
 
         /// Resolve a credit imbalance by depositing into an account
+        /// The credit is already accounted for in total issuance, so issuance is unchanged
+        /// Origin: signed by the imbalance holder
         #[ink(message)]
         pub fn resolve_credit(&mut self, who: AccountId, credit: CreditImbalance) -> Result<()> {
-            if credit.amount == 0 {
+            let amount = self.credit_amount(&credit)?;
+            if amount == 0 {
                 return Ok(());
             }
 
-            // The credit represents tokens that should be added to total issuance
-            self.mint(who, credit.amount)
+            self.deposit_into(
+                NATIVE_ASSET,
+                who,
+                amount,
+                Precision::Exact,
+                Provenance::Extant,
+            )?;
+            self.credits.remove(credit.id);
+
+            self.env().emit_event(Deposit { who, amount });
+
+            Ok(())
         }
 
         /// Settle a debt imbalance by withdrawing from an account
+        /// Any part of the debt that could not be withdrawn is returned as a new debt
+        /// The debt is already accounted for in total issuance, so issuance is unchanged
+        /// Origin: signed by the imbalance holder, who must also be `who` or a trusted caller
         #[ink(message)]
         pub fn settle_debt(
             &mut self,
             who: AccountId,
             debt: DebtImbalance,
             preservation: Preservation,
-        ) -> Result<CreditImbalance> {
-            if debt.amount == 0 {
-                return Ok(CreditImbalance { amount: 0 });
+        ) -> Result<DebtImbalance> {
+            let amount = self.debt_amount(&debt)?;
+            self.ensure_signed_by(who)?;
+            if amount == 0 {
+                return Ok(DebtImbalance::zero());
             }
 
-            // Try to burn the debt amount
-            let burned = self.burn_from(
+            // Try to withdraw the debt amount
+            let withdrawn = self.withdraw_from(
+                NATIVE_ASSET,
                 who,
-                debt.amount,
+                amount,
                 preservation,
                 Precision::BestEffort,
                 Fortitude::Polite,
             )?;
+            self.debts.remove(debt.id);
+
+            if withdrawn > 0 {
+                self.env().emit_event(Withdraw {
+                    who,
+                    amount: withdrawn,
+                });
+            }
 
-            // If we couldn't burn the full amount, return the unburned portion as credit
-            let remaining = debt.amount.saturating_sub(burned);
-            Ok(CreditImbalance { amount: remaining })
+            // If we couldn't withdraw the full amount, the remaining portion is still owed
+            let remaining = amount.saturating_sub(withdrawn);
+            Ok(self.new_debt(remaining))
         }
 
-        /// Get reducible balance considering preservation mode
+        /// Drop a credit imbalance without resolving it
+        /// The credited tokens are removed from total issuance
+        /// The part issued as new tokens is released back to the holder's minting quota
+        /// Origin: signed by the imbalance holder
         #[ink(message)]
-        pub fn reducible_balance(
-            &self,
-            who: AccountId,
-            preservation: Preservation,
-            _force: Fortitude,
-        ) -> Balance {
-            let account = self.account(who);
-            let usable = account.free.saturating_sub(account.frozen);
+        pub fn drop_credit(&mut self, credit: CreditImbalance) -> Result<()> {
+            let amount = self.credit_amount(&credit)?;
+            let minted = self.credit_minted(&credit);
+            self.credits.remove(credit.id);
 
-            match preservation {
-                Preservation::Expendable => usable,
-                Preservation::Preserve | Preservation::Protect => {
-                    usable.saturating_sub(self.existential_deposit)
-                }
-            }
+            self.burn_issuance(NATIVE_ASSET, amount);
+            self.release_quota(minted);
+            Ok(())
         }
 
-        /// Check if a deposit can be made
+        /// Drop a debt imbalance without settling it
+        /// The owed tokens are added back to total issuance, up to the maximum supply
+        /// Origin: signed by the imbalance holder
         #[ink(message)]
-        pub fn can_deposit(
-            &self,
-            who: AccountId,
-            amount: Balance,
-            _provenance: Provenance,
-        ) -> DepositConsequence {
-            if amount == 0 {
-                return DepositConsequence::Success;
-            }
-
-            let account = self.account(who);
+        pub fn drop_debt(&mut self, debt: DebtImbalance) -> Result<()> {
+            let amount = self.debt_amount(&debt)?;
+            self.ensure_can_mint(NATIVE_ASSET, amount)?;
+            self.debts.remove(debt.id);
 
-            // Check if this would cause overflow
-            if let Some(new_balance) = account.free.checked_add(amount) {
-                if let Some(_new_total) = self.total_issuance.checked_add(amount) {
-                    // Check minimum balance for new accounts
-                    if account.free == 0 && new_balance < self.existential_deposit {
-                        return DepositConsequence::BelowMinimum;
-                    }
-                    DepositConsequence::Success
-                } else {
-                    DepositConsequence::Overflow
-                }
-            } else {
-                DepositConsequence::Overflow
-            }
+            self.mint_issuance(NATIVE_ASSET, amount);
+            Ok(())
         }
 
-        /// Check if a withdrawal can be made
+        /// Issue new tokens as a credit not yet assigned to any account
+        /// Total issuance increases immediately, saturating at the maximum supply
+        /// The issued amount is charged against the caller's quota
+        /// Origin: root or the `Minter` role
         #[ink(message)]
-        pub fn can_withdraw(&self, who: AccountId, amount: Balance) -> WithdrawConsequence {
-            if amount == 0 {
-                return WithdrawConsequence::Success;
-            }
+        pub fn issue(&mut self, amount: Balance) -> Result<CreditImbalance> {
+            self.ensure_role(Role::Minter)?;
 
-            let account = self.account(who);
-            let usable = account.free.saturating_sub(account.frozen);
+            let issued = amount.min(self.issuance_headroom(NATIVE_ASSET));
+            self.ensure_quota(issued)?;
+            self.mint_issuance(NATIVE_ASSET, issued);
+            self.consume_quota(issued);
 
-            if usable < amount {
-                return WithdrawConsequence::BalanceLow;
+            if issued > 0 {
+                self.env().emit_event(Issued { amount: issued });
             }
 
-            // Safe to unwrap: we've already verified usable >= amount, and usable <= account.free
-            let new_balance = account.free.saturating_sub(amount);
+            Ok(self.new_credit(issued, issued))
+        }
+
+        /// Rescind tokens from total issuance, returning the debt owed by some account
+        /// Total issuance decreases immediately, saturating at zero
+        /// Origin: root or the `Burner` role
+        #[ink(message)]
+        pub fn rescind(&mut self, amount: Balance) -> Result<DebtImbalance> {
+            self.ensure_role(Role::Burner)?;
+
+            let rescinded = amount.min(self.total_issuance());
+            self.burn_issuance(NATIVE_ASSET, rescinded);
 
-            if new_balance < self.existential_deposit && new_balance > 0 {
-                return WithdrawConsequence::ReducedToZero(new_balance);
+            if rescinded > 0 {
+                self.env().emit_event(Rescinded { amount: rescinded });
             }
 
-            WithdrawConsequence::Success
+            Ok(self.new_debt(rescinded))
         }
 
-        /// Write balance directly, returning any dust that was removed
-        /// This is a low-level operation that bypasses normal checks
+        /// Deposit into an account, returning the matching debt
+        /// Total issuance is unchanged until the debt is dropped
+        /// The deposited amount is charged against the caller's quota
+        /// Origin: root or the `Minter` role
         #[ink(message)]
-        pub fn write_balance(
+        pub fn deposit(
             &mut self,
             who: AccountId,
             amount: Balance,
-        ) -> Result<Option<Balance>> {
-            let caller = self.env().caller();
-            if caller != self.owner {
-                return Err(Error::NotAllowed);
-            }
-
-            let mut account = self.account(who);
-            let old_balance = account.free;
-
-            // Check if new balance is below existential deposit
-            if amount > 0 && amount < self.existential_deposit {
-                // Reap the account and return the dust
-                let dust = amount;
-                account.free = 0;
+            precision: Precision,
+        ) -> Result<DebtImbalance> {
+            self.ensure_role(Role::Minter)?;
+            self.ensure_quota(amount)?;
 
-                // Update total issuance
-                if old_balance > amount {
-                    self.total_issuance = self
-                        .total_issuance
-                        .saturating_sub(old_balance.saturating_sub(amount));
-                    self.active_issuance = self
-                        .active_issuance
-                        .saturating_sub(old_balance.saturating_sub(amount));
-                } else {
-                    let diff = amount.saturating_sub(old_balance);
-                    self.total_issuance = self.total_issuance.saturating_add(diff);
-                    self.active_issuance = self.active_issuance.saturating_add(diff);
-                }
+            let deposited =
+                self.deposit_into(NATIVE_ASSET, who, amount, precision, Provenance::Minted)?;
+            self.consume_quota(deposited);
 
-                self.accounts.insert(who, &account);
-                return Ok(Some(dust));
+            if deposited > 0 {
+                self.env().emit_event(Deposit {
+                    who,
+                    amount: deposited,
+                });
             }
 
-            // Set the new balance
-            account.free = amount;
+            Ok(self.new_debt(deposited))
+        }
 
-            // Update total issuance based on the difference
-            if old_balance > amount {
-                let diff = old_balance.saturating_sub(amount);
-                self.total_issuance = self.total_issuance.saturating_sub(diff);
-                self.active_issuance = self.active_issuance.saturating_sub(diff);
+        /// Withdraw from an account, returning the matching credit
+        /// Total issuance is unchanged until the credit is dropped
+        /// Origin: signed by `who` or a trusted caller
+        #[ink(message)]
+        pub fn withdraw(
+            &mut self,
+            who: AccountId,
+            amount: Balance,
+            precision: Precision,
+            preservation: Preservation,
+            force: Fortitude,
+        ) -> Result<CreditImbalance> {
+            self.ensure_signed_by(who)?;
+
+            let withdrawn =
+                self.withdraw_from(NATIVE_ASSET, who, amount, preservation, precision, force)?;
+
+            if withdrawn > 0 {
+                self.env().emit_event(Withdraw {
+                    who,
+                    amount: withdrawn,
+                });
+            }
+
+            Ok(self.new_credit(withdrawn, 0))
+        }
+
+        /// Split a credit into two, the first holding at most `amount`
+        /// Origin: signed by the imbalance holder
+        #[ink(message)]
+        pub fn split_credit(
+            &mut self,
+            credit: CreditImbalance,
+            amount: Balance,
+        ) -> Result<(CreditImbalance, CreditImbalance)> {
+            let minted = self.credit_minted(&credit);
+            let total = self.take_credit(&credit)?;
+            let first = amount.min(total);
+            let second = total.checked_sub(first).ok_or(Error::Underflow)?;
+            let first_minted = minted.min(first);
+            let second_minted = minted.saturating_sub(first_minted);
+            Ok((
+                self.new_credit(first, first_minted),
+                self.new_credit(second, second_minted),
+            ))
+        }
+
+        /// Split a debt into two, the first holding at most `amount`
+        /// Origin: signed by the imbalance holder
+        #[ink(message)]
+        pub fn split_debt(
+            &mut self,
+            debt: DebtImbalance,
+            amount: Balance,
+        ) -> Result<(DebtImbalance, DebtImbalance)> {
+            let total = self.take_debt(&debt)?;
+            let first = amount.min(total);
+            let second = total.checked_sub(first).ok_or(Error::Underflow)?;
+            Ok((self.new_debt(first), self.new_debt(second)))
+        }
+
+        /// Merge two credits into a new one holding their combined amount
+        /// Origin: signed by the imbalance holder
+        #[ink(message)]
+        pub fn merge_credits(
+            &mut self,
+            credit: CreditImbalance,
+            other: CreditImbalance,
+        ) -> Result<CreditImbalance> {
+            let total = self.combined_credit_amount(&credit, &other)?;
+            let minted = self.combined_credit_minted(&credit, &other);
+            self.credits.remove(credit.id);
+            self.credits.remove(other.id);
+            Ok(self.new_credit(total, minted))
+        }
+
+        /// Merge two debts into a new one holding their combined amount
+        /// Origin: signed by the imbalance holder
+        #[ink(message)]
+        pub fn merge_debts(
+            &mut self,
+            debt: DebtImbalance,
+            other: DebtImbalance,
+        ) -> Result<DebtImbalance> {
+            let total = self.combined_debt_amount(&debt, &other)?;
+            self.debts.remove(debt.id);
+            self.debts.remove(other.id);
+            Ok(self.new_debt(total))
+        }
+
+        /// Absorb a credit into another, keeping the identifier of the first
+        /// Origin: signed by the imbalance holder
+        #[ink(message)]
+        pub fn subsume_credit(
+            &mut self,
+            credit: CreditImbalance,
+            other: CreditImbalance,
+        ) -> Result<CreditImbalance> {
+            let total = self.combined_credit_amount(&credit, &other)?;
+            let minted = self.combined_credit_minted(&credit, &other);
+            self.credits.remove(other.id);
+            if credit.amount == 0 {
+                return Ok(self.new_credit(total, minted));
+            }
+
+            let holder = self.env().caller();
+            self.credits.insert(
+                credit.id,
+                &ImbalanceRecord {
+                    holder,
+                    amount: total,
+                    minted,
+                },
+            );
+            Ok(CreditImbalance {
+                id: credit.id,
+                amount: total,
+            })
+        }
+
+        /// Absorb a debt into another, keeping the identifier of the first
+        /// Origin: signed by the imbalance holder
+        #[ink(message)]
+        pub fn subsume_debt(
+            &mut self,
+            debt: DebtImbalance,
+            other: DebtImbalance,
+        ) -> Result<DebtImbalance> {
+            let total = self.combined_debt_amount(&debt, &other)?;
+            self.debts.remove(other.id);
+            if debt.amount == 0 {
+                return Ok(self.new_debt(total));
+            }
+
+            let holder = self.env().caller();
+            self.debts.insert(
+                debt.id,
+                &ImbalanceRecord {
+                    holder,
+                    amount: total,
+                    minted: 0,
+                },
+            );
+            Ok(DebtImbalance {
+                id: debt.id,
+                amount: total,
+            })
+        }
+
+        /// Offset a credit against a debt, returning whichever side is left over
+        /// Total issuance is unchanged since only the excess remains outstanding
+        /// The part of the credit issued as new tokens that is cancelled out is released back
+        /// to the holder's minting quota
+        /// Origin: signed by the imbalance holder
+        #[ink(message)]
+        pub fn offset(
+            &mut self,
+            credit: CreditImbalance,
+            debt: DebtImbalance,
+        ) -> Result<SameOrOther> {
+            let credit_amount = self.credit_amount(&credit)?;
+            let debt_amount = self.debt_amount(&debt)?;
+            let minted = self.credit_minted(&credit);
+            self.credits.remove(credit.id);
+            self.debts.remove(debt.id);
+
+            if credit_amount > debt_amount {
+                let excess = credit_amount
+                    .checked_sub(debt_amount)
+                    .ok_or(Error::Underflow)?;
+                let kept = minted.min(excess);
+                self.release_quota(minted.saturating_sub(kept));
+                Ok(SameOrOther::Same(self.new_credit(excess, kept)))
+            } else if debt_amount > credit_amount {
+                let excess = debt_amount
+                    .checked_sub(credit_amount)
+                    .ok_or(Error::Underflow)?;
+                self.release_quota(minted);
+                Ok(SameOrOther::Other(self.new_debt(excess)))
+            } else {
+                self.release_quota(minted);
+                Ok(SameOrOther::None)
+            }
+        }
+
+        /// Record a new credit issued to the caller, `minted` of which are new tokens
+        fn new_credit(&mut self, amount: Balance, minted: Balance) -> CreditImbalance {
+            if amount == 0 {
+                return CreditImbalance::zero();
+            }
+            let id = self.next_imbalance_id();
+            let holder = self.env().caller();
+            self.credits.insert(
+                id,
+                &ImbalanceRecord {
+                    holder,
+                    amount,
+                    minted,
+                },
+            );
+            CreditImbalance { id, amount }
+        }
+
+        /// Record a new debt issued to the caller
+        fn new_debt(&mut self, amount: Balance) -> DebtImbalance {
+            if amount == 0 {
+                return DebtImbalance::zero();
+            }
+            let id = self.next_imbalance_id();
+            let holder = self.env().caller();
+            self.debts.insert(
+                id,
+                &ImbalanceRecord {
+                    holder,
+                    amount,
+                    minted: 0,
+                },
+            );
+            DebtImbalance { id, amount }
+        }
+
+        /// Allocate an identifier for a new imbalance record
+        fn next_imbalance_id(&mut self) -> ImbalanceId {
+            let id = self.next_imbalance_id;
+            self.next_imbalance_id = id.wrapping_add(1).max(1);
+            id
+        }
+
+        /// Check that a credit handle matches an outstanding credit held by the caller
+        fn credit_amount(&self, credit: &CreditImbalance) -> Result<Balance> {
+            if credit.amount == 0 {
+                return Ok(0);
+            }
+            match self.credits.get(credit.id) {
+                Some(record)
+                    if record.holder == self.env().caller() && record.amount == credit.amount =>
+                {
+                    Ok(record.amount)
+                }
+                _ => Err(Error::InvalidImbalance),
+            }
+        }
+
+        /// Get the part of a credit issued as new tokens
+        /// The credit is expected to have been checked already
+        fn credit_minted(&self, credit: &CreditImbalance) -> Balance {
+            if credit.amount == 0 {
+                return 0;
+            }
+            self.credits
+                .get(credit.id)
+                .map_or(0, |record| record.minted)
+        }
+
+        /// Get the combined part of two credits issued as new tokens
+        fn combined_credit_minted(
+            &self,
+            credit: &CreditImbalance,
+            other: &CreditImbalance,
+        ) -> Balance {
+            self.credit_minted(credit)
+                .saturating_add(self.credit_minted(other))
+        }
+
+        /// Consume an outstanding credit held by the caller, returning its amount
+        fn take_credit(&mut self, credit: &CreditImbalance) -> Result<Balance> {
+            let amount = self.credit_amount(credit)?;
+            self.credits.remove(credit.id);
+            Ok(amount)
+        }
+
+        /// Check two distinct credits held by the caller and return their combined amount
+        fn combined_credit_amount(
+            &self,
+            credit: &CreditImbalance,
+            other: &CreditImbalance,
+        ) -> Result<Balance> {
+            // The same handle passed twice would otherwise be counted twice
+            if credit.amount > 0 && credit.id == other.id {
+                return Err(Error::InvalidImbalance);
+            }
+            self.credit_amount(credit)?
+                .checked_add(self.credit_amount(other)?)
+                .ok_or(Error::Overflow)
+        }
+
+        /// Check that a debt handle matches an outstanding debt held by the caller
+        fn debt_amount(&self, debt: &DebtImbalance) -> Result<Balance> {
+            if debt.amount == 0 {
+                return Ok(0);
+            }
+            match self.debts.get(debt.id) {
+                Some(record)
+                    if record.holder == self.env().caller() && record.amount == debt.amount =>
+                {
+                    Ok(record.amount)
+                }
+                _ => Err(Error::InvalidImbalance),
+            }
+        }
+
+        /// Consume an outstanding debt held by the caller, returning its amount
+        fn take_debt(&mut self, debt: &DebtImbalance) -> Result<Balance> {
+            let amount = self.debt_amount(debt)?;
+            self.debts.remove(debt.id);
+            Ok(amount)
+        }
+
+        /// Check two distinct debts held by the caller and return their combined amount
+        fn combined_debt_amount(
+            &self,
+            debt: &DebtImbalance,
+            other: &DebtImbalance,
+        ) -> Result<Balance> {
+            // The same handle passed twice would otherwise be counted twice
+            if debt.amount > 0 && debt.id == other.id {
+                return Err(Error::InvalidImbalance);
+            }
+            self.debt_amount(debt)?
+                .checked_add(self.debt_amount(other)?)
+                .ok_or(Error::Overflow)
+        }
+
+        /// Get reducible balance considering preservation mode
+        #[ink(message)]
+        pub fn reducible_balance(
+            &self,
+            who: AccountId,
+            preservation: Preservation,
+            force: Fortitude,
+        ) -> Balance {
+            self.asset_reducible_balance(NATIVE_ASSET, who, preservation, force)
+        }
+
+        /// Get reducible balance of an account of an asset considering preservation mode
+        #[ink(message)]
+        pub fn asset_reducible_balance(
+            &self,
+            asset: AssetId,
+            who: AccountId,
+            preservation: Preservation,
+            force: Fortitude,
+        ) -> Balance {
+            self.reducible_balance_for(asset, who, preservation, force, WithdrawReasons::all())
+        }
+
+        /// Get reducible balance for a kind of withdrawal, ignoring locks that do not apply
+        fn reducible_balance_for(
+            &self,
+            asset: AssetId,
+            who: AccountId,
+            preservation: Preservation,
+            force: Fortitude,
+            reasons: WithdrawReasons,
+        ) -> Balance {
+            let account = self.account_for(asset, who, reasons);
+            let usable = self.liquid_balance(&account, force);
+
+            if account.free == 0 || !self.must_keep_alive(asset, who, preservation) {
+                return usable;
+            }
+
+            let min_balance = self.asset_minimum_balance(asset);
+            if self.legacy_freezes {
+                // The existential deposit comes on top of the frozen balance
+                usable.saturating_sub(min_balance)
+            } else {
+                // Frozen funds also count towards the existential deposit
+                let untouchable = account.free.saturating_sub(usable);
+                account.free.saturating_sub(untouchable.max(min_balance))
+            }
+        }
+
+        /// Get account data with only the locks that apply to the withdrawal reasons frozen
+        /// Locks only exist on the native asset
+        fn account_for(
+            &self,
+            asset: AssetId,
+            who: AccountId,
+            reasons: WithdrawReasons,
+        ) -> AccountData {
+            let mut account = self.asset_account(asset, who);
+            if asset == NATIVE_ASSET && reasons != WithdrawReasons::all() {
+                let locks: Vec<BalanceLock> = self
+                    .locks
+                    .get(who)
+                    .unwrap_or_default()
+                    .into_iter()
+                    .filter(|lock| lock.reasons.intersects(reasons))
+                    .collect();
+                account.frozen = Self::frozen_amount(
+                    &locks,
+                    &self.freezes.get((asset, who)).unwrap_or_default(),
+                );
+            }
+            account
+        }
+
+        /// Get the part of the free balance that may be spent with the given fortitude
+        /// Forced operations may ignore freezes, held funds are never part of the free balance
+        fn liquid_balance(&self, account: &AccountData, force: Fortitude) -> Balance {
+            match force {
+                Fortitude::Polite => account.free.saturating_sub(self.untouchable(account)),
+                Fortitude::Force => account.free,
+            }
+        }
+
+        /// Get the part of the free balance needed to satisfy freezes
+        fn untouchable(&self, account: &AccountData) -> Balance {
+            if self.legacy_freezes {
+                account.frozen
+            } else {
+                // Held funds count towards satisfying freezes
+                account.frozen.saturating_sub(account.reserved)
+            }
+        }
+
+        /// Get the fortitude with which funds may be moved from free to reserved
+        /// Reserving keeps the total balance, so only the legacy model lets freezes prevent it
+        /// Locks with the `RESERVE` reason therefore have no effect in the default model
+        fn reserve_fortitude(&self) -> Fortitude {
+            if self.legacy_freezes {
+                Fortitude::Polite
+            } else {
+                Fortitude::Force
+            }
+        }
+
+        /// Check if a deposit can be made
+        /// Minted deposits must also fit into total issuance, extant funds are already counted
+        #[ink(message)]
+        pub fn can_deposit(
+            &self,
+            who: AccountId,
+            amount: Balance,
+            provenance: Provenance,
+        ) -> DepositConsequence {
+            self.asset_can_deposit(NATIVE_ASSET, who, amount, provenance)
+        }
+
+        /// Check if a deposit of an asset can be made
+        #[ink(message)]
+        pub fn asset_can_deposit(
+            &self,
+            asset: AssetId,
+            who: AccountId,
+            amount: Balance,
+            provenance: Provenance,
+        ) -> DepositConsequence {
+            let Some(details) = self.assets.get(asset) else {
+                return DepositConsequence::UnknownAsset;
+            };
+            if amount == 0 {
+                return DepositConsequence::Success;
+            }
+
+            let account = self.asset_account(asset, who);
+
+            // Check if this would cause overflow
+            let Some(new_balance) = account.free.checked_add(amount) else {
+                return DepositConsequence::Overflow;
+            };
+            if provenance == Provenance::Minted {
+                if details.total_issuance.checked_add(amount).is_none() {
+                    return DepositConsequence::Overflow;
+                }
+                if amount > self.issuance_headroom(asset) {
+                    return DepositConsequence::ExceedsMaxSupply;
+                }
+            }
+
+            // Check requirements for new accounts
+            if account.free == 0 {
+                if new_balance < details.min_balance {
+                    return DepositConsequence::BelowMinimum;
+                }
+                if self.blocked.contains(who) {
+                    return DepositConsequence::CannotCreate;
+                }
+            }
+
+            DepositConsequence::Success
+        }
+
+        /// Ensure a deposit can be made, turning a failed check into an error
+        fn ensure_can_deposit(
+            &self,
+            asset: AssetId,
+            who: AccountId,
+            amount: Balance,
+            provenance: Provenance,
+        ) -> Result<()> {
+            match self.asset_can_deposit(asset, who, amount, provenance) {
+                DepositConsequence::Success => Ok(()),
+                DepositConsequence::Overflow => Err(Error::Overflow),
+                DepositConsequence::BelowMinimum => Err(Error::ExistentialDeposit),
+                DepositConsequence::CannotCreate => Err(Error::CannotCreate),
+                DepositConsequence::UnknownAsset => Err(Error::UnknownAsset),
+                DepositConsequence::ExceedsMaxSupply => Err(Error::MaxSupplyExceeded),
+            }
+        }
+
+        /// Clamp a best-effort deposit to what fits into the account and total issuance
+        fn max_deposit(
+            &self,
+            asset: AssetId,
+            who: AccountId,
+            amount: Balance,
+            provenance: Provenance,
+        ) -> Balance {
+            let account = self.asset_account(asset, who);
+            let mut max = amount.min(Balance::MAX.saturating_sub(account.free));
+            if provenance == Provenance::Minted {
+                max = max.min(self.issuance_headroom(asset));
+            }
+            max
+        }
+
+        /// Block or unblock the creation of an account
+        /// Blocked accounts that already exist keep working
+        /// Origin: root or the `Freezer` role
+        #[ink(message)]
+        pub fn set_blocked(&mut self, who: AccountId, blocked: bool) -> Result<()> {
+            self.ensure_role(Role::Freezer)?;
+
+            if blocked {
+                self.blocked.insert(who, &());
+            } else {
+                self.blocked.remove(who);
+            }
+            Ok(())
+        }
+
+        /// Check if the creation of an account is blocked
+        #[ink(message)]
+        pub fn is_blocked(&self, who: AccountId) -> bool {
+            self.blocked.contains(who)
+        }
+
+        /// Check if a withdrawal can be made
+        #[ink(message)]
+        pub fn can_withdraw(&self, who: AccountId, amount: Balance) -> WithdrawConsequence {
+            self.asset_can_withdraw(NATIVE_ASSET, who, amount)
+        }
+
+        /// Check if a withdrawal of an asset can be made
+        #[ink(message)]
+        pub fn asset_can_withdraw(
+            &self,
+            asset: AssetId,
+            who: AccountId,
+            amount: Balance,
+        ) -> WithdrawConsequence {
+            self.withdraw_consequence(
+                asset,
+                who,
+                amount,
+                Preservation::Expendable,
+                Fortitude::Polite,
+                WithdrawReasons::all(),
+            )
+        }
+
+        /// Decide the consequence of a withdrawal, shared by every path that takes free funds
+        fn withdraw_consequence(
+            &self,
+            asset: AssetId,
+            who: AccountId,
+            amount: Balance,
+            preservation: Preservation,
+            force: Fortitude,
+            reasons: WithdrawReasons,
+        ) -> WithdrawConsequence {
+            let Some(details) = self.assets.get(asset) else {
+                return WithdrawConsequence::UnknownAsset;
+            };
+            if amount == 0 {
+                return WithdrawConsequence::Success;
+            }
+
+            if details.total_issuance.checked_sub(amount).is_none() {
+                return WithdrawConsequence::Underflow;
+            }
+
+            let account = self.account_for(asset, who, reasons);
+            let Some(new_balance) = account.free.checked_sub(amount) else {
+                return WithdrawConsequence::BalanceLow;
+            };
+
+            // Check whether the account may die
+            let success = if new_balance < details.min_balance {
+                if self.must_keep_alive(asset, who, preservation) {
+                    return WithdrawConsequence::WouldDie;
+                }
+                WithdrawConsequence::ReducedToZero(new_balance)
+            } else {
+                WithdrawConsequence::Success
+            };
+
+            // The remaining free balance must still cover the frozen balance
+            let frozen = account
+                .free
+                .saturating_sub(self.liquid_balance(&account, force));
+            if new_balance < frozen {
+                return WithdrawConsequence::Frozen;
+            }
+
+            success
+        }
+
+        /// Ensure a withdrawal can be made, turning a failed check into an error
+        fn ensure_can_withdraw_from(
+            &self,
+            asset: AssetId,
+            who: AccountId,
+            amount: Balance,
+            preservation: Preservation,
+            force: Fortitude,
+            reasons: WithdrawReasons,
+        ) -> Result<()> {
+            // Like frame's decrease_balance, funds beyond the free balance are unavailable
+            // regardless of the total issuance
+            if self.assets.contains(asset) && amount > self.asset_account(asset, who).free {
+                return Err(Error::InsufficientBalance);
+            }
+
+            match self.withdraw_consequence(asset, who, amount, preservation, force, reasons) {
+                WithdrawConsequence::Success | WithdrawConsequence::ReducedToZero(_) => Ok(()),
+                WithdrawConsequence::BalanceLow => Err(Error::InsufficientBalance),
+                WithdrawConsequence::Frozen => Err(Error::LiquidityRestrictions),
+                WithdrawConsequence::WouldDie => Err(Error::Expendability),
+                WithdrawConsequence::Underflow => Err(Error::Underflow),
+                WithdrawConsequence::UnknownAsset => Err(Error::UnknownAsset),
+            }
+        }
+
+        /// Write balance directly, returning any dust that was removed
+        /// This is a low-level operation that bypasses normal checks
+        /// The dust stays pending for the caller until it is passed to `handle_raw_dust`
+        /// Origin: root or the `Admin` role
+        #[ink(message)]
+        pub fn write_balance(
+            &mut self,
+            who: AccountId,
+            amount: Balance,
+        ) -> Result<Option<Balance>> {
+            self.ensure_role(Role::Admin)?;
+
+            let mut account = self.account(who);
+            let old_balance = account.free;
+
+            // Check if new balance is below existential deposit
+            if amount > 0 && amount < self.existential_deposit() {
+                // Reap the account and return the dust
+                let dust = amount;
+                account.free = 0;
+
+                // Update total issuance
+                if old_balance > amount {
+                    self.burn_issuance(NATIVE_ASSET, old_balance.saturating_sub(amount));
+                } else {
+                    let diff = amount.saturating_sub(old_balance);
+                    self.ensure_can_mint(NATIVE_ASSET, diff)?;
+                    self.mint_issuance(NATIVE_ASSET, diff);
+                }
+
+                self.store_account(NATIVE_ASSET, who, &account);
+
+                // The caller is expected to hand the dust to `handle_raw_dust`
+                let caller = self.env().caller();
+                let pending = self.raw_dust(caller).saturating_add(dust);
+                self.raw_dust.insert(caller, &pending);
+                return Ok(Some(dust));
+            }
+
+            // Set the new balance
+            account.free = amount;
+
+            // Update total issuance based on the difference
+            if old_balance > amount {
+                self.burn_issuance(NATIVE_ASSET, old_balance.saturating_sub(amount));
+            } else {
+                let diff = amount.saturating_sub(old_balance);
+                self.ensure_can_mint(NATIVE_ASSET, diff)?;
+                self.mint_issuance(NATIVE_ASSET, diff);
+            }
+
+            self.store_account(NATIVE_ASSET, who, &account);
+            Ok(None)
+        }
+
+        /// Decrease balance (Unbalanced trait equivalent)
+        /// Origin: trusted caller
+        #[ink(message)]
+        pub fn decrease_balance(
+            &mut self,
+            who: AccountId,
+            amount: Balance,
+            precision: Precision,
+            preservation: Preservation,
+            fortitude: Fortitude,
+        ) -> Result<Balance> {
+            self.burn_from(who, amount, preservation, precision, fortitude)
+        }
+
+        /// Increase balance (Unbalanced trait equivalent)
+        /// The increase is charged against the caller's minting quota
+        /// Origin: trusted caller
+        #[ink(message)]
+        pub fn increase_balance(
+            &mut self,
+            who: AccountId,
+            amount: Balance,
+            precision: Precision,
+        ) -> Result<Balance> {
+            self.ensure_trusted()?;
+            self.do_increase_balance(who, amount, precision)
+        }
+
+        /// Increase balance without an origin check, charging the caller's minting quota
+        fn do_increase_balance(
+            &mut self,
+            who: AccountId,
+            amount: Balance,
+            precision: Precision,
+        ) -> Result<Balance> {
+            if amount == 0 {
+                return Ok(0);
+            }
+
+            // Saturate at the max value when Precision::BestEffort
+            let amount = match precision {
+                Precision::Exact => amount,
+                Precision::BestEffort => {
+                    self.max_deposit(NATIVE_ASSET, who, amount, Provenance::Minted)
+                }
+            };
+
+            match self.can_deposit(who, amount, Provenance::Minted) {
+                DepositConsequence::BelowMinimum if precision == Precision::BestEffort => {
+                    return Ok(0)
+                }
+                _ => self.ensure_can_deposit(NATIVE_ASSET, who, amount, Provenance::Minted)?,
+            }
+
+            if amount == 0 {
+                return Ok(0);
+            }
+            self.ensure_quota(amount)?;
+
+            let mut account = self.account(who);
+            account.free = account.free.saturating_add(amount);
+            self.mint_issuance(NATIVE_ASSET, amount);
+            self.store_account(NATIVE_ASSET, who, &account);
+            self.consume_quota(amount);
+
+            Ok(amount)
+        }
+
+        /// Set total issuance directly (low-level operation)
+        /// Origin: root or the `IssuanceManager` role
+        #[ink(message)]
+        pub fn set_total_issuance(&mut self, amount: Balance) -> Result<()> {
+            self.ensure_role(Role::IssuanceManager)?;
+            if amount > self.max_issuance(NATIVE_ASSET) {
+                return Err(Error::MaxSupplyExceeded);
+            }
+
+            // Inactive issuance is kept, so the change only affects active issuance
+            let mut details = self.details(NATIVE_ASSET);
+            details.total_issuance = amount;
+            self.store_issuance(NATIVE_ASSET, details);
+            Ok(())
+        }
+
+        /// Deactivate some issuance
+        /// This removes the amount from active circulation but keeps it in total issuance
+        /// At most the active issuance can be deactivated
+        /// Origin: root or the `IssuanceManager` role
+        #[ink(message)]
+        pub fn deactivate(&mut self, amount: Balance) -> Result<()> {
+            self.ensure_role(Role::IssuanceManager)?;
+
+            let deactivated = amount.min(self.active_issuance());
+            if deactivated == 0 {
+                return Ok(());
+            }
+
+            let mut details = self.details(NATIVE_ASSET);
+            details.inactive_issuance = details.inactive_issuance.saturating_add(deactivated);
+            self.assets.insert(NATIVE_ASSET, &details);
+
+            self.env().emit_event(Deactivated {
+                amount: deactivated,
+            });
+            Ok(())
+        }
+
+        /// Reactivate some issuance
+        /// This adds the amount back to active circulation, at most the inactive issuance
+        /// Origin: root or the `IssuanceManager` role
+        #[ink(message)]
+        pub fn reactivate(&mut self, amount: Balance) -> Result<()> {
+            self.ensure_role(Role::IssuanceManager)?;
+
+            let reactivated = amount.min(self.inactive_issuance());
+            if reactivated == 0 {
+                return Ok(());
+            }
+
+            let mut details = self.details(NATIVE_ASSET);
+            details.inactive_issuance = details.inactive_issuance.saturating_sub(reactivated);
+            self.assets.insert(NATIVE_ASSET, &details);
+
+            self.env().emit_event(Reactivated {
+                amount: reactivated,
+            });
+            Ok(())
+        }
+
+        /// Mint new tokens to an account
+        /// The amount is charged against the caller's minting quota
+        /// Origin: root or the `Minter` role
+        #[ink(message)]
+        pub fn mint(&mut self, to: AccountId, amount: Balance) -> Result<()> {
+            self.asset_mint(NATIVE_ASSET, to, amount)
+        }
+
+        /// Mint new tokens of an asset to an account
+        /// Origin: signed by the asset owner, or root or the `Minter` role for the native asset
+        #[ink(message)]
+        pub fn asset_mint(&mut self, asset: AssetId, to: AccountId, amount: Balance) -> Result<()> {
+            self.ensure_asset(asset)?;
+            if asset != NATIVE_ASSET {
+                if self.env().caller() != self.details(asset).owner {
+                    return Err(Error::NotAllowed);
+                }
+                return self.mint_into(asset, to, amount);
+            }
+
+            self.ensure_role(Role::Minter)?;
+            self.ensure_quota(amount)?;
+            self.mint_into(asset, to, amount)?;
+            self.consume_quota(amount);
+            Ok(())
+        }
+
+        /// Set the minting quota of an account, starting a fresh window
+        /// Origin: root or the `Admin` role
+        #[ink(message)]
+        pub fn set_minter_quota(
+            &mut self,
+            minter: AccountId,
+            allowance: Balance,
+            window_cap: Balance,
+        ) -> Result<()> {
+            self.ensure_role(Role::Admin)?;
+            self.minter_quotas.insert(
+                minter,
+                &MinterQuota {
+                    allowance,
+                    window_cap,
+                    window_start: self.env().block_number(),
+                    window_minted: 0,
+                },
+            );
+            Ok(())
+        }
+
+        /// Set the length in blocks of a quota window, zero disables the window caps
+        /// Origin: root or the `Admin` role
+        #[ink(message)]
+        pub fn set_quota_window(&mut self, blocks: BlockNumber) -> Result<()> {
+            self.ensure_role(Role::Admin)?;
+            self.quota_window = blocks;
+            Ok(())
+        }
+
+        /// Get the minting quota of an account
+        #[ink(message)]
+        pub fn minter_quota(&self, minter: AccountId) -> Option<MinterQuota> {
+            self.minter_quotas.get(minter)
+        }
+
+        /// Get the length in blocks of a quota window
+        #[ink(message)]
+        pub fn quota_window(&self) -> BlockNumber {
+            self.quota_window
+        }
+
+        /// Amount an account may mint right now
+        /// The owner is not limited, accounts without a quota may not mint at all
+        #[ink(message)]
+        pub fn remaining_quota(&self, minter: AccountId) -> Balance {
+            if minter == self.owner {
+                return Balance::MAX;
+            }
+            let Some(quota) = self.minter_quotas.get(minter) else {
+                return 0;
+            };
+            let in_window = if self.quota_window == 0 {
+                Balance::MAX
+            } else if self.window_expired(&quota) {
+                quota.window_cap
+            } else {
+                quota.window_cap.saturating_sub(quota.window_minted)
+            };
+            quota.allowance.min(in_window)
+        }
+
+        /// Whether the window of a quota has run out, so the next mint starts a new one
+        fn window_expired(&self, quota: &MinterQuota) -> bool {
+            self.env().block_number() >= quota.window_start.saturating_add(self.quota_window)
+        }
+
+        /// Fail if minting the amount would exceed the caller's quota
+        fn ensure_quota(&self, amount: Balance) -> Result<()> {
+            if amount > self.remaining_quota(self.env().caller()) {
+                return Err(Error::MinterQuotaExceeded);
+            }
+            Ok(())
+        }
+
+        /// Charge a minted amount against the caller's quota
+        fn consume_quota(&mut self, amount: Balance) {
+            let caller = self.env().caller();
+            if caller == self.owner || amount == 0 {
+                return;
+            }
+            let Some(mut quota) = self.minter_quotas.get(caller) else {
+                return;
+            };
+            if self.window_expired(&quota) {
+                quota.window_start = self.env().block_number();
+                quota.window_minted = 0;
+            }
+            quota.allowance = quota.allowance.saturating_sub(amount);
+            quota.window_minted = quota.window_minted.saturating_add(amount);
+            self.minter_quotas.insert(caller, &quota);
+        }
+
+        /// Give an amount charged by a cancelled mint back to the caller's quota
+        fn release_quota(&mut self, amount: Balance) {
+            let caller = self.env().caller();
+            if caller == self.owner || amount == 0 {
+                return;
+            }
+            let Some(mut quota) = self.minter_quotas.get(caller) else {
+                return;
+            };
+            quota.allowance = quota.allowance.saturating_add(amount);
+            if !self.window_expired(&quota) {
+                quota.window_minted = quota.window_minted.saturating_sub(amount);
+            }
+            self.minter_quotas.insert(caller, &quota);
+        }
+
+        /// Internal mint function
+        fn mint_into(&mut self, asset: AssetId, to: AccountId, amount: Balance) -> Result<()> {
+            if amount == 0 {
+                return Ok(());
+            }
+
+            self.ensure_can_deposit(asset, to, amount, Provenance::Minted)?;
+
+            let mut account = self.asset_account(asset, to);
+            account.free = account.free.saturating_add(amount);
+            self.mint_issuance(asset, amount);
+
+            self.store_account(asset, to, &account);
+
+            Ok(())
+        }
+
+        /// Burn tokens from an account
+        /// Origin: trusted caller
+        #[ink(message)]
+        pub fn burn_from(
+            &mut self,
+            who: AccountId,
+            amount: Balance,
+            preservation: Preservation,
+            precision: Precision,
+            force: Fortitude,
+        ) -> Result<Balance> {
+            self.asset_burn_from(NATIVE_ASSET, who, amount, preservation, precision, force)
+        }
+
+        /// Burn tokens of an asset from an account
+        /// Origin: trusted caller
+        #[ink(message)]
+        pub fn asset_burn_from(
+            &mut self,
+            asset: AssetId,
+            who: AccountId,
+            amount: Balance,
+            preservation: Preservation,
+            precision: Precision,
+            force: Fortitude,
+        ) -> Result<Balance> {
+            self.ensure_trusted()?;
+            self.ensure_asset(asset)?;
+            self.do_burn_from(asset, who, amount, preservation, precision, force)
+        }
+
+        /// Burn tokens of an asset from an account once the origin has been checked
+        fn do_burn_from(
+            &mut self,
+            asset: AssetId,
+            who: AccountId,
+            amount: Balance,
+            preservation: Preservation,
+            precision: Precision,
+            force: Fortitude,
+        ) -> Result<Balance> {
+            let actual_burn =
+                self.withdraw_from(asset, who, amount, preservation, precision, force)?;
+
+            if actual_burn == 0 {
+                return Ok(0);
+            }
+
+            self.burn_issuance(asset, actual_burn);
+
+            self.env().emit_event(Burned {
+                asset,
+                who,
+                amount: actual_burn,
+            });
+
+            Ok(actual_burn)
+        }
+
+        /// Increase the free balance of an account without changing total issuance
+        fn deposit_into(
+            &mut self,
+            asset: AssetId,
+            who: AccountId,
+            amount: Balance,
+            precision: Precision,
+            provenance: Provenance,
+        ) -> Result<Balance> {
+            if amount == 0 {
+                return Ok(0);
+            }
+
+            let actual = match precision {
+                Precision::Exact => amount,
+                Precision::BestEffort => self.max_deposit(asset, who, amount, provenance),
+            };
+
+            match self.asset_can_deposit(asset, who, actual, provenance) {
+                DepositConsequence::BelowMinimum if precision == Precision::BestEffort => {
+                    return Ok(0)
+                }
+                _ => self.ensure_can_deposit(asset, who, actual, provenance)?,
+            }
+
+            let mut account = self.asset_account(asset, who);
+            account.free = account.free.saturating_add(actual);
+            self.store_account(asset, who, &account);
+
+            Ok(actual)
+        }
+
+        /// Decrease the free balance of an account without changing total issuance
+        /// Any dust left behind is handled as part of the withdrawal
+        fn withdraw_from(
+            &mut self,
+            asset: AssetId,
+            who: AccountId,
+            amount: Balance,
+            preservation: Preservation,
+            precision: Precision,
+            force: Fortitude,
+        ) -> Result<Balance> {
+            if amount == 0 {
+                return Ok(0);
+            }
+
+            let actual_burn = match precision {
+                Precision::Exact => amount,
+                Precision::BestEffort => {
+                    amount.min(self.asset_reducible_balance(asset, who, preservation, force))
+                }
+            };
+
+            if actual_burn == 0 {
+                return Ok(0);
+            }
+
+            // Check if the burn would violate any restriction BEFORE doing it
+            self.ensure_can_withdraw_from(
+                asset,
+                who,
+                actual_burn,
+                preservation,
+                force,
+                WithdrawReasons::all(),
+            )?;
+
+            let mut account = self.asset_account(asset, who);
+            account.free = account.free.saturating_sub(actual_burn);
+
+            // Handle dust for Expendable case only
+            // Note: The preservation check above ensures we only reach here with Expendable
+            // when dust needs handling
+            if account.free < self.asset_minimum_balance(asset) && account.free > 0 {
+                self.collect_dust(asset, who, &mut account)?;
+            } else {
+                self.store_account(asset, who, &account);
+            }
+
+            Ok(actual_burn)
+        }
+
+        /// Restore balance (mint without ED check, typically used for system operations)
+        /// Origin: trusted caller
+        #[ink(message)]
+        pub fn restore(&mut self, who: AccountId, amount: Balance) -> Result<()> {
+            self.asset_restore(NATIVE_ASSET, who, amount)
+        }
+
+        /// Restore balance of an asset
+        /// The restored amount is charged against the caller's minting quota
+        /// Origin: trusted caller
+        #[ink(message)]
+        pub fn asset_restore(
+            &mut self,
+            asset: AssetId,
+            who: AccountId,
+            amount: Balance,
+        ) -> Result<()> {
+            self.ensure_trusted()?;
+            self.ensure_asset(asset)?;
+            if amount == 0 {
+                return Ok(());
+            }
+
+            let mut account = self.asset_account(asset, who);
+
+            // Check minimum balance for new accounts
+            if account.free == 0 && amount < self.asset_minimum_balance(asset) {
+                return Err(Error::ExistentialDeposit);
+            }
+
+            self.ensure_can_mint(asset, amount)?;
+            self.ensure_quota(amount)?;
+            account.free = account.free.checked_add(amount).ok_or(Error::Overflow)?;
+            self.mint_issuance(asset, amount);
+
+            self.store_account(asset, who, &account);
+            self.consume_quota(amount);
+
+            self.env().emit_event(Restored { asset, who, amount });
+
+            Ok(())
+        }
+
+        /// Shelve balance (burn without affecting total issuance tracking)
+        /// Origin: trusted caller
+        #[ink(message)]
+        pub fn shelve(&mut self, who: AccountId, amount: Balance) -> Result<()> {
+            self.asset_shelve(NATIVE_ASSET, who, amount)
+        }
+
+        /// Shelve balance of an asset
+        /// Origin: trusted caller
+        #[ink(message)]
+        pub fn asset_shelve(
+            &mut self,
+            asset: AssetId,
+            who: AccountId,
+            amount: Balance,
+        ) -> Result<()> {
+            self.ensure_trusted()?;
+            self.ensure_asset(asset)?;
+            if amount == 0 {
+                return Ok(());
+            }
+
+            let mut account = self.asset_account(asset, who);
+
+            if account.free < amount {
+                return Err(Error::InsufficientBalance);
+            }
+
+            account.free = account
+                .free
+                .checked_sub(amount)
+                .ok_or(Error::InsufficientBalance)?;
+
+            self.burn_issuance(asset, amount);
+            self.store_account(asset, who, &account);
+
+            self.env().emit_event(Shelved { asset, who, amount });
+
+            Ok(())
+        }
+
+        /// Set balance directly (combines mint/burn as needed)
+        /// Origin: trusted caller
+        #[ink(message)]
+        pub fn set_balance(&mut self, who: AccountId, amount: Balance) -> Result<Balance> {
+            self.asset_set_balance(NATIVE_ASSET, who, amount)
+        }
+
+        /// Set balance of an asset directly, returning the resulting free balance
+        /// A change that cannot be applied leaves the balance untouched
+        /// Any minted difference is charged against the caller's minting quota
+        /// Origin: trusted caller
+        #[ink(message)]
+        pub fn asset_set_balance(
+            &mut self,
+            asset: AssetId,
+            who: AccountId,
+            amount: Balance,
+        ) -> Result<Balance> {
+            self.ensure_trusted()?;
+            self.ensure_asset(asset)?;
+            Ok(self.do_set_balance(asset, who, amount))
+        }
+
+        /// Set balance of an asset without an origin check, returning the resulting free balance
+        /// Any minted difference is charged against the caller's minting quota
+        fn do_set_balance(&mut self, asset: AssetId, who: AccountId, amount: Balance) -> Balance {
+            let mut account = self.asset_account(asset, who);
+            let current = account.free;
+
+            if amount == current {
+                return current;
+            }
+
+            if amount > current {
+                // Mint
+                let to_mint = amount.saturating_sub(current);
+                if self.ensure_can_mint(asset, to_mint).is_ok()
+                    && self.ensure_quota(to_mint).is_ok()
+                {
+                    if let Some(new_balance) = account.free.checked_add(to_mint) {
+                        self.mint_issuance(asset, to_mint);
+                        account.free = new_balance;
+                        self.store_account(asset, who, &account);
+                        self.consume_quota(to_mint);
+                        return new_balance;
+                    }
+                }
+            } else {
+                // Burn
+                let to_burn = current.saturating_sub(amount);
+                if let Some(new_balance) = account.free.checked_sub(to_burn) {
+                    self.burn_issuance(asset, to_burn);
+                    account.free = new_balance;
+                    self.store_account(asset, who, &account);
+                    return new_balance;
+                }
+            }
+
+            current
+        }
+
+        /// Deposit into an account, creating it if needed (legacy Currency::deposit_creating)
+        /// Failures such as a deposit below the existential deposit deposit nothing
+        /// Origin: root or the `Minter` role
+        #[ink(message)]
+        pub fn deposit_creating(&mut self, who: AccountId, amount: Balance) -> Result<Balance> {
+            self.ensure_role(Role::Minter)?;
+
+            Ok(self
+                .do_increase_balance(who, amount, Precision::Exact)
+                .unwrap_or(0))
+        }
+
+        /// Deposit into an account that already exists (legacy Currency::deposit_into_existing)
+        /// Origin: root or the `Minter` role
+        #[ink(message)]
+        pub fn deposit_into_existing(
+            &mut self,
+            who: AccountId,
+            amount: Balance,
+        ) -> Result<Balance> {
+            self.ensure_role(Role::Minter)?;
+
+            if amount == 0 {
+                return Ok(0);
+            }
+
+            if self.total_balance(who) == 0 {
+                return Err(Error::DeadAccount);
+            }
+
+            self.do_increase_balance(who, amount, Precision::Exact)
+        }
+
+        /// Set the free balance of an account, minting or burning the difference
+        /// (legacy Currency::make_free_balance_be)
+        /// A new account is not created below the existential deposit, and an existing
+        /// account left below it is reaped
+        /// Origin: root or the `Admin` role
+        #[ink(message)]
+        pub fn make_free_balance_be(&mut self, who: AccountId, amount: Balance) -> Result<Balance> {
+            self.ensure_role(Role::Admin)?;
+
+            let existential_deposit = self.existential_deposit();
+            let reserved = self.reserved_balance(who);
+            if self.total_balance(who) == 0 && amount.saturating_add(reserved) < existential_deposit
+            {
+                return Ok(0);
+            }
+
+            self.do_set_balance(NATIVE_ASSET, who, amount);
+
+            let mut account = self.account(who);
+            if account.free > 0
+                && account.free.saturating_add(account.reserved) < existential_deposit
+            {
+                self.collect_dust(NATIVE_ASSET, who, &mut account)?;
+            }
+
+            Ok(account.free)
+        }
+
+        /// Check that the locks applying to the withdrawal reasons allow leaving
+        /// `new_balance` as the free balance (legacy Currency::ensure_can_withdraw)
+        #[ink(message)]
+        pub fn ensure_can_withdraw(
+            &self,
+            who: AccountId,
+            amount: Balance,
+            reasons: WithdrawReasons,
+            new_balance: Balance,
+        ) -> Result<()> {
+            if amount == 0 {
+                return Ok(());
+            }
+
+            let account = self.account_for(NATIVE_ASSET, who, reasons);
+            if new_balance < self.untouchable(&account) {
+                return Err(Error::LiquidityRestrictions);
+            }
+
+            Ok(())
+        }
+
+        /// Withdraw and burn from the free balance for the given reasons (legacy Currency::withdraw)
+        /// With `KeepAlive` the withdrawal fails if it would take an account from at or
+        /// above the existential deposit to below it
+        /// Origin: signed by `who` or a trusted caller
+        #[ink(message)]
+        pub fn currency_withdraw(
+            &mut self,
+            who: AccountId,
+            amount: Balance,
+            reasons: WithdrawReasons,
+            liveness: ExistenceRequirement,
+        ) -> Result<Balance> {
+            self.ensure_signed_by(who)?;
+
+            if amount == 0 {
+                return Ok(0);
+            }
+
+            let free = self.free_balance(who);
+            let new_free = free.checked_sub(amount).ok_or(Error::InsufficientBalance)?;
+
+            let existential_deposit = self.existential_deposit();
+            let would_kill = new_free < existential_deposit && free >= existential_deposit;
+            if liveness == ExistenceRequirement::KeepAlive && would_kill {
+                return Err(Error::Expendability);
+            }
+
+            self.ensure_can_withdraw(who, amount, reasons, new_free)?;
+
+            // Locks were checked for the given reasons only, so the burn itself ignores them
+            self.do_burn_from(
+                NATIVE_ASSET,
+                who,
+                amount,
+                Preservation::Expendable,
+                Precision::Exact,
+                Fortitude::Force,
+            )
+        }
+
+        /// Transfer tokens from caller to another account
+        /// Origin: signed by the sender
+        #[ink(message)]
+        pub fn transfer(&mut self, to: AccountId, amount: Balance) -> Result<()> {
+            let from = self.env().caller();
+            self.do_transfer(
+                NATIVE_ASSET,
+                from,
+                to,
+                amount,
+                Preservation::Expendable,
+                Fortitude::Polite,
+            )
+        }
+
+        /// Transfer keeping the sender alive (won't go below ED)
+        /// Origin: signed by the sender
+        #[ink(message)]
+        pub fn transfer_keep_alive(&mut self, to: AccountId, amount: Balance) -> Result<()> {
+            let from = self.env().caller();
+            self.do_transfer(
+                NATIVE_ASSET,
+                from,
+                to,
+                amount,
+                Preservation::Preserve,
+                Fortitude::Polite,
+            )
+        }
+
+        /// Transfer with explicit preservation mode
+        /// Origin: signed by the sender
+        #[ink(message)]
+        pub fn transfer_with_preservation(
+            &mut self,
+            to: AccountId,
+            amount: Balance,
+            preservation: Preservation,
+        ) -> Result<()> {
+            self.asset_transfer(NATIVE_ASSET, to, amount, preservation)
+        }
+
+        /// Transfer tokens of an asset from caller to another account
+        /// Origin: signed by the sender
+        #[ink(message)]
+        pub fn asset_transfer(
+            &mut self,
+            asset: AssetId,
+            to: AccountId,
+            amount: Balance,
+            preservation: Preservation,
+        ) -> Result<()> {
+            self.ensure_asset(asset)?;
+            let from = self.env().caller();
+            self.do_transfer(asset, from, to, amount, preservation, Fortitude::Polite)
+        }
+
+        /// Internal transfer function
+        fn do_transfer(
+            &mut self,
+            asset: AssetId,
+            from: AccountId,
+            to: AccountId,
+            amount: Balance,
+            preservation: Preservation,
+            force: Fortitude,
+        ) -> Result<()> {
+            if amount == 0 {
+                return Ok(());
+            }
+
+            // Handle self-transfer as a no-op
+            if from == to {
+                self.env().emit_event(Transfer {
+                    asset,
+                    from: Some(from),
+                    to: Some(to),
+                    value: amount,
+                });
+                return Ok(());
+            }
+
+            // Check the sender with the same rules as any other withdrawal
+            self.ensure_can_withdraw_from(
+                asset,
+                from,
+                amount,
+                preservation,
+                force,
+                WithdrawReasons::TRANSFER,
+            )?;
+
+            // The funds already exist, so only the receiving account is checked
+            self.ensure_can_deposit(asset, to, amount, Provenance::Extant)?;
+
+            let mut from_account = self.asset_account(asset, from);
+            let mut to_account = self.asset_account(asset, to);
+
+            // Perform transfer
+            from_account.free = from_account
+                .free
+                .checked_sub(amount)
+                .ok_or(Error::InsufficientBalance)?;
+            to_account.free = to_account.free.saturating_add(amount);
+
+            // The recipient is stored first, as the dust of the sender may be routed to it
+            self.store_account(asset, to, &to_account);
+
+            // Handle dust when the account is allowed to die
+            // Note: The preservation check above ensures we only reach here with dust
+            // when the account does not have to be kept alive
+            if from_account.free < self.asset_minimum_balance(asset) && from_account.free > 0 {
+                self.collect_dust(asset, from, &mut from_account)?;
+            } else {
+                self.store_account(asset, from, &from_account);
+            }
+
+            self.env().emit_event(Transfer {
+                asset,
+                from: Some(from),
+                to: Some(to),
+                value: amount,
+            });
+
+            Ok(())
+        }
+
+        /// Store the data of an account, reaping it once it holds nothing and has no references
+        /// Reaping also removes the locks and freezes of the account
+        /// Endowed is emitted when the account is created and Killed when it is reaped
+        fn store_account(&mut self, asset: AssetId, who: AccountId, account: &AccountData) {
+            let was_alive = self
+                .accounts
+                .get((asset, who))
+                .is_some_and(|old| old.free > 0 || old.reserved > 0);
+            let is_alive = account.free > 0 || account.reserved > 0;
+
+            if !is_alive && !self.has_refs(asset, who) {
+                self.accounts.remove((asset, who));
+                self.freezes.remove((asset, who));
+                if asset == NATIVE_ASSET {
+                    self.locks.remove(who);
+                }
+                if was_alive {
+                    self.env().emit_event(Killed {
+                        asset,
+                        account: who,
+                    });
+                }
+                return;
+            }
+
+            self.accounts.insert((asset, who), account);
+            if is_alive && !was_alive {
+                self.env().emit_event(Endowed {
+                    asset,
+                    account: who,
+                    free_balance: account.free,
+                });
+            }
+        }
+
+        /// Whether references added through the refcount messages keep an account around
+        /// Only native accounts carry references
+        fn has_refs(&self, asset: AssetId, who: AccountId) -> bool {
+            asset == NATIVE_ASSET
+                && self
+                    .refs
+                    .get(who)
+                    .is_some_and(|refs| refs != AccountRefs::default())
+        }
+
+        /// Check whether an account exists, i.e. holds a balance or has references
+        #[ink(message)]
+        pub fn account_exists(&self, who: AccountId) -> bool {
+            self.asset_account_exists(NATIVE_ASSET, who)
+        }
+
+        /// Check whether an account of an asset exists
+        #[ink(message)]
+        pub fn asset_account_exists(&self, asset: AssetId, who: AccountId) -> bool {
+            let account = self.asset_account(asset, who);
+            account.free > 0 || account.reserved > 0 || self.has_refs(asset, who)
+        }
+
+        /// Collect the free balance of an account being reaped as dust
+        /// The account is stored before the dust is routed, so a dust target that is the
+        /// account itself is not overwritten
+        fn collect_dust(
+            &mut self,
+            asset: AssetId,
+            who: AccountId,
+            account: &mut AccountData,
+        ) -> Result<()> {
+            let dust = core::mem::take(&mut account.free);
+            self.store_account(asset, who, account);
+            self.route_dust(asset, who, dust)
+        }
+
+        /// Route dust already taken from an account according to the dust policy
+        /// Only burning changes total issuance, the other policies keep the dust in existence
+        fn route_dust(&mut self, asset: AssetId, who: AccountId, amount: Balance) -> Result<()> {
+            if amount == 0 {
+                return Ok(());
+            }
+
+            let destination = match self.dust_policy {
+                DustPolicy::Burn => {
+                    self.burn_issuance(asset, amount);
+                    self.env().emit_event(DustLost {
+                        asset,
+                        account: who,
+                        amount,
+                    });
+                    return Ok(());
+                }
+                DustPolicy::Trap(trap) => {
+                    let mut trap_account = self.asset_account(asset, trap);
+                    trap_account.free = trap_account
+                        .free
+                        .checked_add(amount)
+                        .ok_or(Error::Overflow)?;
+                    self.store_account(asset, trap, &trap_account);
+                    Some(trap)
+                }
+                DustPolicy::Treasury(treasury) => {
+                    let mut treasury_account = self.asset_account(asset, treasury);
+                    let mut holds = self.holds.get((asset, treasury)).unwrap_or_default();
+                    Self::add_to_hold(&mut holds, DUST_HOLD_REASON, amount, self.max_holds)?;
+                    treasury_account.reserved = treasury_account
+                        .reserved
+                        .checked_add(amount)
+                        .ok_or(Error::Overflow)?;
+                    self.store_holds(asset, treasury, &holds);
+                    self.store_account(asset, treasury, &treasury_account);
+                    Some(treasury)
+                }
+                DustPolicy::Pot => {
+                    let pot = self.dust_pot(asset);
+                    self.dust_pots
+                        .insert(asset, &pot.checked_add(amount).ok_or(Error::Overflow)?);
+                    None
+                }
+            };
+
+            self.env().emit_event(DustTrapped {
+                asset,
+                account: who,
+                destination,
+                amount,
+            });
+            Ok(())
+        }
+
+        /// Handle dust that the caller removed from an account through `write_balance`,
+        /// according to the dust policy
+        /// Only dust still pending for the caller is handled, any excess is ignored
+        /// Origin: trusted caller
+        #[ink(message)]
+        pub fn handle_raw_dust(&mut self, amount: Balance) -> Result<()> {
+            self.ensure_trusted()?;
+            let caller = self.env().caller();
+            let pending = self.raw_dust(caller);
+            let amount = amount.min(pending);
+            if amount == 0 {
+                return Ok(());
+            }
+
+            self.route_dust(NATIVE_ASSET, caller, amount)?;
+            let remaining = pending.saturating_sub(amount);
+            if remaining == 0 {
+                self.raw_dust.remove(caller);
+            } else {
+                self.raw_dust.insert(caller, &remaining);
+            }
+            Ok(())
+        }
+
+        /// Get the dust returned to a caller by `write_balance` that it has not handled yet
+        #[ink(message)]
+        pub fn raw_dust(&self, who: AccountId) -> Balance {
+            self.raw_dust.get(who).unwrap_or(0)
+        }
+
+        /// Reap an account whose free balance is below the existential deposit,
+        /// handling the remainder as dust, and return the amount of dust
+        /// Accounts at or above the existential deposit are left untouched
+        /// Origin: trusted caller
+        #[ink(message)]
+        pub fn handle_dust(&mut self, who: AccountId) -> Result<Balance> {
+            self.ensure_trusted()?;
+            let mut account = self.account(who);
+            let dust = account.free;
+            if dust == 0 || dust >= self.existential_deposit() {
+                return Ok(0);
+            }
+
+            self.collect_dust(NATIVE_ASSET, who, &mut account)?;
+            Ok(dust)
+        }
+
+        /// Reserve some balance from an account
+        /// Origin: trusted caller
+        #[ink(message)]
+        pub fn reserve(&mut self, who: AccountId, amount: Balance) -> Result<()> {
+            self.ensure_trusted()?;
+            if amount == 0 {
+                return Ok(());
+            }
+
+            if !self.can_reserve(who, amount) {
+                return Err(Error::InsufficientBalance);
+            }
+
+            let mut account = self.account(who);
+
+            account.free = account
+                .free
+                .checked_sub(amount)
+                .ok_or(Error::InsufficientBalance)?;
+            account.reserved = account
+                .reserved
+                .checked_add(amount)
+                .ok_or(Error::Overflow)?;
+
+            self.store_account(NATIVE_ASSET, who, &account);
+
+            self.env().emit_event(Reserved { who, amount });
+
+            Ok(())
+        }
+
+        /// Check if some balance can be reserved (legacy ReservableCurrency::can_reserve)
+        /// Locks only apply with legacy freezes, and then only those with the `RESERVE` reason
+        #[ink(message)]
+        pub fn can_reserve(&self, who: AccountId, amount: Balance) -> bool {
+            amount == 0
+                || self.liquid_balance(
+                    &self.account_for(NATIVE_ASSET, who, WithdrawReasons::RESERVE),
+                    self.reserve_fortitude(),
+                ) >= amount
+        }
+
+        /// Unreserve some of the anonymous reserve of an account, leaving named reserves and holds
+        /// Origin: trusted caller
+        #[ink(message)]
+        pub fn unreserve(&mut self, who: AccountId, amount: Balance) -> Result<Balance> {
+            self.ensure_trusted()?;
+            if amount == 0 {
+                return Ok(0);
+            }
+
+            let mut account = self.account(who);
+            let holds = self.holds.get((NATIVE_ASSET, who)).unwrap_or_default();
+            let reserves = self.reserves.get(who).unwrap_or_default();
+            let actual = amount.min(Self::anonymous_reserve(&account, &holds, &reserves));
+
+            account.reserved = account.reserved.saturating_sub(actual);
+            account.free = account.free.checked_add(actual).ok_or(Error::Overflow)?;
+
+            self.store_account(NATIVE_ASSET, who, &account);
+
+            self.env().emit_event(Unreserved {
+                who,
+                amount: actual,
+            });
+
+            Ok(actual)
+        }
+
+        /// Get the amount reserved on an account under a specific identifier
+        #[ink(message)]
+        pub fn reserved_balance_named(&self, id: ReserveIdentifier, who: AccountId) -> Balance {
+            self.reserves
+                .get(who)
+                .unwrap_or_default()
+                .iter()
+                .find(|reserve| reserve.id == id)
+                .map_or(0, |reserve| reserve.amount)
+        }
+
+        /// Reserve some balance from an account under an identifier
+        /// Origin: trusted caller
+        #[ink(message)]
+        pub fn reserve_named(
+            &mut self,
+            id: ReserveIdentifier,
+            who: AccountId,
+            amount: Balance,
+        ) -> Result<()> {
+            self.ensure_trusted()?;
+            if amount == 0 {
+                return Ok(());
+            }
+
+            let mut reserves = self.reserves.get(who).unwrap_or_default();
+            Self::add_to_reserve(&mut reserves, id, amount, self.max_reserves)?;
+
+            self.reserve(who, amount)?;
+            self.store_reserves(who, &reserves);
+
+            Ok(())
+        }
+
+        /// Unreserve some balance reserved under an identifier
+        /// Returns the amount actually unreserved
+        /// Origin: trusted caller
+        #[ink(message)]
+        pub fn unreserve_named(
+            &mut self,
+            id: ReserveIdentifier,
+            who: AccountId,
+            amount: Balance,
+        ) -> Result<Balance> {
+            self.ensure_trusted()?;
+            if amount == 0 {
+                return Ok(0);
+            }
+
+            let mut account = self.account(who);
+            let mut reserves = self.reserves.get(who).unwrap_or_default();
+            let actual = Self::take_from_reserves(&mut reserves, Some(id), amount);
+
+            account.reserved = account.reserved.saturating_sub(actual);
+            account.free = account.free.checked_add(actual).ok_or(Error::Overflow)?;
+
+            self.store_reserves(who, &reserves);
+            self.store_account(NATIVE_ASSET, who, &account);
+
+            if actual > 0 {
+                self.env().emit_event(Unreserved {
+                    who,
+                    amount: actual,
+                });
+            }
+
+            Ok(actual)
+        }
+
+        /// Get the amount held on an account for a specific reason
+        #[ink(message)]
+        pub fn balance_on_hold(&self, reason: HoldReason, who: AccountId) -> Balance {
+            self.asset_balance_on_hold(NATIVE_ASSET, reason, who)
+        }
+
+        /// Get the amount of an asset held on an account for a specific reason
+        #[ink(message)]
+        pub fn asset_balance_on_hold(
+            &self,
+            asset: AssetId,
+            reason: HoldReason,
+            who: AccountId,
+        ) -> Balance {
+            self.holds
+                .get((asset, who))
+                .unwrap_or_default()
+                .iter()
+                .find(|hold| hold.id == reason)
+                .map_or(0, |hold| hold.amount)
+        }
+
+        /// Get the total amount held on an account (all holds plus anonymous reserves)
+        #[ink(message)]
+        pub fn total_balance_on_hold(&self, who: AccountId) -> Balance {
+            self.asset_total_balance_on_hold(NATIVE_ASSET, who)
+        }
+
+        /// Get the total amount of an asset held on an account
+        #[ink(message)]
+        pub fn asset_total_balance_on_hold(&self, asset: AssetId, who: AccountId) -> Balance {
+            self.asset_account(asset, who).reserved
+        }
+
+        /// Check if an amount can be placed on hold for the given reason
+        #[ink(message)]
+        pub fn can_hold(&self, reason: HoldReason, who: AccountId, amount: Balance) -> bool {
+            self.asset_can_hold(NATIVE_ASSET, reason, who, amount)
+        }
+
+        /// Check if an amount of an asset can be placed on hold for the given reason
+        #[ink(message)]
+        pub fn asset_can_hold(
+            &self,
+            asset: AssetId,
+            reason: HoldReason,
+            who: AccountId,
+            amount: Balance,
+        ) -> bool {
+            self.ensure_can_hold(asset, reason, who, amount).is_ok()
+        }
+
+        /// Place an amount on hold for the given reason
+        /// The held amount moves from free to reserved and the account is kept alive
+        /// Origin: trusted caller
+        #[ink(message)]
+        pub fn hold(&mut self, reason: HoldReason, who: AccountId, amount: Balance) -> Result<()> {
+            self.asset_hold(NATIVE_ASSET, reason, who, amount)
+        }
+
+        /// Place an amount of an asset on hold for the given reason
+        /// Origin: trusted caller
+        #[ink(message)]
+        pub fn asset_hold(
+            &mut self,
+            asset: AssetId,
+            reason: HoldReason,
+            who: AccountId,
+            amount: Balance,
+        ) -> Result<()> {
+            self.ensure_trusted()?;
+            self.ensure_asset(asset)?;
+            if amount == 0 {
+                return Ok(());
+            }
+
+            self.ensure_can_hold(asset, reason, who, amount)?;
+
+            let mut account = self.asset_account(asset, who);
+            let mut holds = self.holds.get((asset, who)).unwrap_or_default();
+
+            account.free = account
+                .free
+                .checked_sub(amount)
+                .ok_or(Error::InsufficientBalance)?;
+            account.reserved = account
+                .reserved
+                .checked_add(amount)
+                .ok_or(Error::Overflow)?;
+
+            match holds.iter_mut().find(|hold| hold.id == reason) {
+                Some(hold) => {
+                    hold.amount = hold.amount.checked_add(amount).ok_or(Error::Overflow)?;
+                }
+                None => holds.push(IdAmount { id: reason, amount }),
+            }
+
+            self.holds.insert((asset, who), &holds);
+            self.store_account(asset, who, &account);
+
+            self.env().emit_event(Held {
+                asset,
+                reason,
+                who,
+                amount,
+            });
+
+            Ok(())
+        }
+
+        /// Release an amount held for the given reason back to the free balance
+        /// Origin: trusted caller
+        #[ink(message)]
+        pub fn release(
+            &mut self,
+            reason: HoldReason,
+            who: AccountId,
+            amount: Balance,
+            precision: Precision,
+        ) -> Result<Balance> {
+            self.asset_release(NATIVE_ASSET, reason, who, amount, precision)
+        }
+
+        /// Release an amount of an asset held for the given reason back to the free balance
+        /// Origin: trusted caller
+        #[ink(message)]
+        pub fn asset_release(
+            &mut self,
+            asset: AssetId,
+            reason: HoldReason,
+            who: AccountId,
+            amount: Balance,
+            precision: Precision,
+        ) -> Result<Balance> {
+            self.ensure_trusted()?;
+            self.ensure_asset(asset)?;
+            self.do_release(asset, reason, who, amount, precision)
+        }
+
+        /// Release held funds of an asset once the origin has been checked
+        fn do_release(
+            &mut self,
+            asset: AssetId,
+            reason: HoldReason,
+            who: AccountId,
+            amount: Balance,
+            precision: Precision,
+        ) -> Result<Balance> {
+            let held = self.asset_balance_on_hold(asset, reason, who);
+            let actual = match precision {
+                Precision::Exact => {
+                    if held < amount {
+                        return Err(Error::InsufficientBalance);
+                    }
+                    amount
+                }
+                Precision::BestEffort => amount.min(held),
+            };
+
+            if actual == 0 {
+                return Ok(0);
+            }
+
+            let mut account = self.asset_account(asset, who);
+            account.free = account.free.checked_add(actual).ok_or(Error::Overflow)?;
+            account.reserved = account.reserved.saturating_sub(actual);
+
+            let mut holds = self.holds.get((asset, who)).unwrap_or_default();
+            if let Some(hold) = holds.iter_mut().find(|hold| hold.id == reason) {
+                hold.amount = hold.amount.saturating_sub(actual);
+            }
+            holds.retain(|hold| hold.amount > 0);
+
+            self.store_holds(asset, who, &holds);
+            self.store_account(asset, who, &account);
+
+            self.env().emit_event(Released {
+                asset,
+                reason,
+                who,
+                amount: actual,
+            });
+
+            Ok(actual)
+        }
+
+        /// Check that a hold can be placed without exceeding the hold limit or killing the account
+        fn ensure_can_hold(
+            &self,
+            asset: AssetId,
+            reason: HoldReason,
+            who: AccountId,
+            amount: Balance,
+        ) -> Result<()> {
+            let holds = self.holds.get((asset, who)).unwrap_or_default();
+            if holds.len() >= self.max_holds as usize && !holds.iter().any(|hold| hold.id == reason)
+            {
+                return Err(Error::TooManyHolds);
+            }
+
+            if amount
+                > self.reducible_balance_for(
+                    asset,
+                    who,
+                    Preservation::Protect,
+                    self.reserve_fortitude(),
+                    WithdrawReasons::RESERVE,
+                )
+            {
+                return Err(Error::InsufficientBalance);
+            }
+
+            Ok(())
+        }
+
+        /// Set the amount held for a reason directly (UnbalancedHold trait equivalent)
+        /// This is a low-level operation: the free balance is untouched and total issuance
+        /// is adjusted by the difference
+        /// Origin: root or the `Admin` role
+        #[ink(message)]
+        pub fn set_balance_on_hold(
+            &mut self,
+            reason: HoldReason,
+            who: AccountId,
+            amount: Balance,
+        ) -> Result<()> {
+            self.ensure_role(Role::Admin)?;
+
+            let mut holds = self.holds.get((NATIVE_ASSET, who)).unwrap_or_default();
+            let held = holds
+                .iter()
+                .find(|hold| hold.id == reason)
+                .map_or(0, |hold| hold.amount);
+
+            if amount == held {
+                return Ok(());
+            }
+            if held == 0 && holds.len() >= self.max_holds as usize {
+                return Err(Error::TooManyHolds);
+            }
+
+            let mut account = self.account(who);
+            if amount > held {
+                let diff = amount.saturating_sub(held);
+                self.ensure_can_mint(NATIVE_ASSET, diff)?;
+                account.reserved = account.reserved.checked_add(diff).ok_or(Error::Overflow)?;
+                self.mint_issuance(NATIVE_ASSET, diff);
             } else {
-                let diff = amount.saturating_sub(old_balance);
-                self.total_issuance = self
-                    .total_issuance
-                    .checked_add(diff)
-                    .ok_or(Error::Overflow)?;
-                self.active_issuance = self
-                    .active_issuance
-                    .checked_add(diff)
-                    .ok_or(Error::Overflow)?;
+                let diff = held.saturating_sub(amount);
+                account.reserved = account.reserved.saturating_sub(diff);
+                self.burn_issuance(NATIVE_ASSET, diff);
             }
 
-            self.accounts.insert(who, &account);
-            Ok(None)
+            match holds.iter_mut().find(|hold| hold.id == reason) {
+                Some(hold) => hold.amount = amount,
+                None => holds.push(IdAmount { id: reason, amount }),
+            }
+            holds.retain(|hold| hold.amount > 0);
+
+            self.store_holds(NATIVE_ASSET, who, &holds);
+            self.store_account(NATIVE_ASSET, who, &account);
+
+            Ok(())
         }
 
-        /// Decrease balance (Unbalanced trait equivalent)
+        /// Increase the amount held for a reason directly, returning the actual increase
+        /// Origin: root or the `Admin` role
+        #[ink(message)]
+        pub fn increase_balance_on_hold(
+            &mut self,
+            reason: HoldReason,
+            who: AccountId,
+            amount: Balance,
+            precision: Precision,
+        ) -> Result<Balance> {
+            self.ensure_role(Role::Admin)?;
+
+            let held = self.balance_on_hold(reason, who);
+            let max = Balance::MAX
+                .saturating_sub(self.account(who).reserved)
+                .min(Balance::MAX.saturating_sub(self.total_issuance()));
+            let actual = match precision {
+                Precision::Exact => {
+                    if amount > max {
+                        return Err(Error::Overflow);
+                    }
+                    amount
+                }
+                Precision::BestEffort => amount.min(max).min(self.issuance_headroom(NATIVE_ASSET)),
+            };
+
+            if actual == 0 {
+                return Ok(0);
+            }
+
+            self.set_balance_on_hold(reason, who, held.saturating_add(actual))?;
+            Ok(actual)
+        }
+
+        /// Decrease the amount held for a reason directly, returning the actual decrease
+        /// Origin: root or the `Admin` role
+        #[ink(message)]
+        pub fn decrease_balance_on_hold(
+            &mut self,
+            reason: HoldReason,
+            who: AccountId,
+            amount: Balance,
+            precision: Precision,
+        ) -> Result<Balance> {
+            self.ensure_role(Role::Admin)?;
+
+            let held = self.balance_on_hold(reason, who);
+            let actual = match precision {
+                Precision::Exact => {
+                    if held < amount {
+                        return Err(Error::InsufficientBalance);
+                    }
+                    amount
+                }
+                Precision::BestEffort => amount.min(held),
+            };
+
+            if actual == 0 {
+                return Ok(0);
+            }
+
+            self.set_balance_on_hold(reason, who, held.saturating_sub(actual))?;
+            Ok(actual)
+        }
+
+        /// Get the part of the held balance that may be moved without breaching freezes
+        #[ink(message)]
+        pub fn reducible_total_balance_on_hold(&self, who: AccountId, force: Fortitude) -> Balance {
+            self.asset_reducible_total_balance_on_hold(NATIVE_ASSET, who, force)
+        }
+
+        /// Get the part of the held balance of an asset that may be moved without breaching freezes
+        #[ink(message)]
+        pub fn asset_reducible_total_balance_on_hold(
+            &self,
+            asset: AssetId,
+            who: AccountId,
+            force: Fortitude,
+        ) -> Balance {
+            let account = self.asset_account(asset, who);
+            let unavailable = match force {
+                // Freezes apply to the total balance, so the free balance covers them first
+                Fortitude::Polite if !self.legacy_freezes => {
+                    account.frozen.saturating_sub(account.free)
+                }
+                _ => 0,
+            };
+            account.reserved.saturating_sub(unavailable)
+        }
+
+        /// Transfer funds held for a reason to another account
+        /// Depending on the restriction the funds arrive free or held for the same reason
+        /// Origin: trusted caller
+        #[ink(message)]
+        #[allow(clippy::too_many_arguments)]
+        pub fn transfer_on_hold(
+            &mut self,
+            reason: HoldReason,
+            source: AccountId,
+            dest: AccountId,
+            amount: Balance,
+            precision: Precision,
+            restriction: Restriction,
+            force: Fortitude,
+        ) -> Result<Balance> {
+            self.asset_transfer_on_hold(
+                NATIVE_ASSET,
+                reason,
+                source,
+                dest,
+                amount,
+                precision,
+                restriction,
+                force,
+            )
+        }
+
+        /// Transfer funds of an asset held for a reason to another account
+        /// Origin: trusted caller
+        #[ink(message)]
+        #[allow(clippy::too_many_arguments)]
+        pub fn asset_transfer_on_hold(
+            &mut self,
+            asset: AssetId,
+            reason: HoldReason,
+            source: AccountId,
+            dest: AccountId,
+            amount: Balance,
+            precision: Precision,
+            restriction: Restriction,
+            force: Fortitude,
+        ) -> Result<Balance> {
+            self.ensure_asset(asset)?;
+            self.ensure_trusted()?;
+
+            let held = self.asset_balance_on_hold(asset, reason, source);
+            let liquid = self.asset_reducible_total_balance_on_hold(asset, source, force);
+            let actual = match precision {
+                Precision::Exact => {
+                    if amount > held {
+                        return Err(Error::InsufficientBalance);
+                    }
+                    if amount > liquid {
+                        return Err(Error::LiquidityRestrictions);
+                    }
+                    amount
+                }
+                Precision::BestEffort => amount.min(liquid).min(held),
+            };
+
+            if actual == 0 {
+                return Ok(0);
+            }
+
+            // Moving funds to the same account only changes where they are kept
+            if source == dest {
+                if restriction == Restriction::Free {
+                    self.do_release(asset, reason, source, actual, Precision::Exact)?;
+                }
+                return Ok(actual);
+            }
+
+            self.ensure_can_deposit(asset, dest, actual, Provenance::Extant)?;
+            let mut dest_holds = self.holds.get((asset, dest)).unwrap_or_default();
+            if restriction == Restriction::OnHold {
+                Self::add_to_hold(&mut dest_holds, reason, actual, self.max_holds)?;
+            }
+
+            let mut source_account = self.asset_account(asset, source);
+            let mut source_holds = self.holds.get((asset, source)).unwrap_or_default();
+            Self::take_from_holds(&mut source_holds, Some(reason), actual);
+            source_account.reserved = source_account.reserved.saturating_sub(actual);
+            self.store_holds(asset, source, &source_holds);
+            self.store_account(asset, source, &source_account);
+
+            let mut dest_account = self.asset_account(asset, dest);
+            match restriction {
+                Restriction::Free => dest_account.free = dest_account.free.saturating_add(actual),
+                Restriction::OnHold => {
+                    dest_account.reserved = dest_account
+                        .reserved
+                        .checked_add(actual)
+                        .ok_or(Error::Overflow)?;
+                    self.store_holds(asset, dest, &dest_holds);
+                }
+            }
+            self.store_account(asset, dest, &dest_account);
+
+            self.env().emit_event(TransferOnHold {
+                asset,
+                reason,
+                source,
+                dest,
+                amount: actual,
+            });
+
+            Ok(actual)
+        }
+
+        /// Transfer free funds to another account and place them on hold there
+        /// The source account is checked like any other transfer
+        /// Origin: trusted caller
+        #[ink(message)]
+        #[allow(clippy::too_many_arguments)]
+        pub fn transfer_and_hold(
+            &mut self,
+            reason: HoldReason,
+            source: AccountId,
+            dest: AccountId,
+            amount: Balance,
+            precision: Precision,
+            preservation: Preservation,
+            force: Fortitude,
+        ) -> Result<Balance> {
+            self.asset_transfer_and_hold(
+                NATIVE_ASSET,
+                reason,
+                source,
+                dest,
+                amount,
+                precision,
+                preservation,
+                force,
+            )
+        }
+
+        /// Transfer free funds of an asset to another account and place them on hold there
+        /// Origin: trusted caller
+        #[ink(message)]
+        #[allow(clippy::too_many_arguments)]
+        pub fn asset_transfer_and_hold(
+            &mut self,
+            asset: AssetId,
+            reason: HoldReason,
+            source: AccountId,
+            dest: AccountId,
+            amount: Balance,
+            precision: Precision,
+            preservation: Preservation,
+            force: Fortitude,
+        ) -> Result<Balance> {
+            self.ensure_asset(asset)?;
+            self.ensure_trusted()?;
+
+            let actual = match precision {
+                Precision::Exact => amount,
+                Precision::BestEffort => amount.min(self.reducible_balance_for(
+                    asset,
+                    source,
+                    preservation,
+                    force,
+                    WithdrawReasons::TRANSFER,
+                )),
+            };
+
+            if actual == 0 {
+                return Ok(0);
+            }
+
+            // Check both sides before any funds move
+            self.ensure_can_withdraw_from(
+                asset,
+                source,
+                actual,
+                preservation,
+                force,
+                WithdrawReasons::TRANSFER,
+            )?;
+            self.ensure_can_deposit(asset, dest, actual, Provenance::Extant)?;
+            let mut dest_holds = self.holds.get((asset, dest)).unwrap_or_default();
+            Self::add_to_hold(&mut dest_holds, reason, actual, self.max_holds)?;
+
+            self.withdraw_from(asset, source, actual, preservation, Precision::Exact, force)?;
+
+            let mut dest_account = self.asset_account(asset, dest);
+            dest_account.reserved = dest_account
+                .reserved
+                .checked_add(actual)
+                .ok_or(Error::Overflow)?;
+            self.store_holds(asset, dest, &dest_holds);
+            self.store_account(asset, dest, &dest_account);
+
+            self.env().emit_event(TransferAndHold {
+                asset,
+                reason,
+                source,
+                dest,
+                amount: actual,
+            });
+
+            Ok(actual)
+        }
+
+        /// Add an amount to the hold for a reason, respecting the hold limit
+        fn add_to_hold(
+            holds: &mut Vec<IdAmount>,
+            reason: HoldReason,
+            amount: Balance,
+            max_holds: u32,
+        ) -> Result<()> {
+            match holds.iter_mut().find(|hold| hold.id == reason) {
+                Some(hold) => {
+                    hold.amount = hold.amount.checked_add(amount).ok_or(Error::Overflow)?;
+                }
+                None => {
+                    if holds.len() >= max_holds as usize {
+                        return Err(Error::TooManyHolds);
+                    }
+                    holds.push(IdAmount { id: reason, amount });
+                }
+            }
+            Ok(())
+        }
+
+        /// Store the holds of an account, removing the entry once there are none left
+        fn store_holds(&mut self, asset: AssetId, who: AccountId, holds: &Vec<IdAmount>) {
+            if holds.is_empty() {
+                self.holds.remove((asset, who));
+            } else {
+                self.holds.insert((asset, who), holds);
+            }
+        }
+
+        /// Slash an account, taking from the free balance, then the anonymous reserve,
+        /// then named reserves, then holds
+        /// Freezes are ignored and the account may be reaped
+        /// Returns the slashed funds as a credit and the amount that could not be slashed
+        /// Origin: root or the `Burner` role
+        #[ink(message)]
+        pub fn slash(
+            &mut self,
+            who: AccountId,
+            amount: Balance,
+        ) -> Result<(CreditImbalance, Balance)> {
+            self.ensure_role(Role::Burner)?;
+
+            let mut account = self.account(who);
+            let mut holds = self.holds.get((NATIVE_ASSET, who)).unwrap_or_default();
+            let mut reserves = self.reserves.get(who).unwrap_or_default();
+
+            let from_free = amount.min(account.free);
+            account.free = account.free.saturating_sub(from_free);
+            let mut remaining = amount.saturating_sub(from_free);
+
+            let from_reserve = remaining.min(Self::anonymous_reserve(&account, &holds, &reserves));
+            remaining = remaining.saturating_sub(from_reserve);
+
+            let from_named = Self::take_from_reserves(&mut reserves, None, remaining);
+            remaining = remaining.saturating_sub(from_named);
+
+            let from_holds = Self::take_from_holds(&mut holds, None, remaining);
+            remaining = remaining.saturating_sub(from_holds);
+
+            account.reserved = account.reserved.saturating_sub(
+                from_reserve
+                    .saturating_add(from_named)
+                    .saturating_add(from_holds),
+            );
+
+            self.store_reserves(who, &reserves);
+            let credit =
+                self.finish_slash(who, account, holds, amount.saturating_sub(remaining))?;
+            Ok((credit, remaining))
+        }
+
+        /// Slash the anonymous reserve of an account, leaving named reserves and holds untouched
+        /// Returns the slashed funds as a credit and the amount that could not be slashed
+        /// Origin: root or the `Burner` role
+        #[ink(message)]
+        pub fn slash_reserved(
+            &mut self,
+            who: AccountId,
+            amount: Balance,
+        ) -> Result<(CreditImbalance, Balance)> {
+            self.ensure_role(Role::Burner)?;
+
+            let mut account = self.account(who);
+            let holds = self.holds.get((NATIVE_ASSET, who)).unwrap_or_default();
+            let reserves = self.reserves.get(who).unwrap_or_default();
+
+            let slashed = amount.min(Self::anonymous_reserve(&account, &holds, &reserves));
+            account.reserved = account.reserved.saturating_sub(slashed);
+
+            let credit = self.finish_slash(who, account, holds, slashed)?;
+            Ok((credit, amount.saturating_sub(slashed)))
+        }
+
+        /// Slash the balance held on an account for the given reason
+        /// Returns the slashed funds as a credit and the amount that could not be slashed
+        /// Origin: root or the `Burner` role
         #[ink(message)]
-        pub fn decrease_balance(
+        pub fn slash_held(
             &mut self,
+            reason: HoldReason,
             who: AccountId,
             amount: Balance,
-            precision: Precision,
-            preservation: Preservation,
-            fortitude: Fortitude,
-        ) -> Result<Balance> {
-            self.burn_from(who, amount, preservation, precision, fortitude)
+        ) -> Result<(CreditImbalance, Balance)> {
+            self.ensure_role(Role::Burner)?;
+
+            let mut account = self.account(who);
+            let mut holds = self.holds.get((NATIVE_ASSET, who)).unwrap_or_default();
+
+            let slashed = Self::take_from_holds(&mut holds, Some(reason), amount);
+            account.reserved = account.reserved.saturating_sub(slashed);
+
+            let credit = self.finish_slash(who, account, holds, slashed)?;
+            Ok((credit, amount.saturating_sub(slashed)))
         }
 
-        /// Increase balance (Unbalanced trait equivalent)
+        /// Move reserved funds of an account to the free or reserved balance of another
+        /// Returns the amount that could not be moved
+        /// Origin: root or the `Admin` role
         #[ink(message)]
-        pub fn increase_balance(
+        pub fn repatriate_reserved(
             &mut self,
-            who: AccountId,
+            from: AccountId,
+            to: AccountId,
             amount: Balance,
-            precision: Precision,
+            status: BalanceStatus,
         ) -> Result<Balance> {
-            if amount == 0 {
-                return Ok(0);
-            }
-
-            let mut account = self.account(who);
+            self.ensure_role(Role::Admin)?;
 
-            // Check minimum balance for new accounts
-            if account.free == 0 && amount < self.existential_deposit {
-                return match precision {
-                    Precision::Exact => Err(Error::ExistentialDeposit),
-                    Precision::BestEffort => Ok(0),
+            // Repatriating to the same account only makes sense into the free balance
+            if from == to {
+                let account = self.account(from);
+                let holds = self.holds.get((NATIVE_ASSET, from)).unwrap_or_default();
+                let reserves = self.reserves.get(from).unwrap_or_default();
+                let reserve = Self::anonymous_reserve(&account, &holds, &reserves);
+                return match status {
+                    BalanceStatus::Free => {
+                        let unreserved = self.unreserve(from, amount.min(reserve))?;
+                        Ok(amount.saturating_sub(unreserved))
+                    }
+                    BalanceStatus::Reserved => Ok(amount.saturating_sub(reserve)),
                 };
             }
 
-            // Check for overflow
-            let new_balance = match account.free.checked_add(amount) {
-                Some(b) => b,
-                None => {
-                    return match precision {
-                        Precision::Exact => Err(Error::Overflow),
-                        Precision::BestEffort => {
-                            // Saturate at max value
-                            let actual = Balance::MAX.saturating_sub(account.free);
-                            account.free = Balance::MAX;
-                            self.total_issuance = self.total_issuance.saturating_add(actual);
-                            self.active_issuance = self.active_issuance.saturating_add(actual);
-                            self.accounts.insert(who, &account);
-                            Ok(actual)
-                        }
-                    };
-                }
-            };
+            let mut to_account = self.account(to);
+            if to_account.free.saturating_add(to_account.reserved) == 0 {
+                return Err(Error::DeadAccount);
+            }
 
-            // Check total issuance overflow
-            if self.total_issuance.checked_add(amount).is_none() {
-                return match precision {
-                    Precision::Exact => Err(Error::Overflow),
-                    Precision::BestEffort => {
-                        let actual = Balance::MAX.saturating_sub(self.total_issuance);
-                        account.free = account.free.saturating_add(actual);
-                        self.total_issuance = Balance::MAX;
-                        self.active_issuance = self.active_issuance.saturating_add(actual);
-                        self.accounts.insert(who, &account);
-                        Ok(actual)
-                    }
-                };
+            let mut from_account = self.account(from);
+            let holds = self.holds.get((NATIVE_ASSET, from)).unwrap_or_default();
+            let reserves = self.reserves.get(from).unwrap_or_default();
+            let actual = amount.min(Self::anonymous_reserve(&from_account, &holds, &reserves));
+
+            match status {
+                BalanceStatus::Free => {
+                    to_account.free = to_account.free.checked_add(actual).ok_or(Error::Overflow)?
+                }
+                BalanceStatus::Reserved => {
+                    to_account.reserved = to_account
+                        .reserved
+                        .checked_add(actual)
+                        .ok_or(Error::Overflow)?
+                }
             }
+            from_account.reserved = from_account.reserved.saturating_sub(actual);
 
-            account.free = new_balance;
-            self.total_issuance = self.total_issuance.saturating_add(amount);
-            self.active_issuance = self.active_issuance.saturating_add(amount);
-            self.accounts.insert(who, &account);
+            self.store_account(NATIVE_ASSET, from, &from_account);
+            self.store_account(NATIVE_ASSET, to, &to_account);
 
-            self.env().emit_event(Endowed {
-                account: who,
-                free_balance: account.free,
+            self.env().emit_event(ReserveRepatriated {
+                from,
+                to,
+                amount: actual,
+                destination_status: status,
             });
 
-            Ok(amount)
+            Ok(amount.saturating_sub(actual))
         }
 
-        /// Set total issuance directly (low-level operation)
+        /// Slash the balance reserved on an account under an identifier
+        /// Returns the slashed funds as a credit and the amount that could not be slashed
+        /// Origin: root or the `Burner` role
         #[ink(message)]
-        pub fn set_total_issuance(&mut self, amount: Balance) -> Result<()> {
-            let caller = self.env().caller();
-            if caller != self.owner {
-                return Err(Error::NotAllowed);
-            }
+        pub fn slash_reserved_named(
+            &mut self,
+            id: ReserveIdentifier,
+            who: AccountId,
+            amount: Balance,
+        ) -> Result<(CreditImbalance, Balance)> {
+            self.ensure_role(Role::Burner)?;
 
-            let old_total = self.total_issuance;
-            self.total_issuance = amount;
+            let mut account = self.account(who);
+            let holds = self.holds.get((NATIVE_ASSET, who)).unwrap_or_default();
+            let mut reserves = self.reserves.get(who).unwrap_or_default();
 
-            // Adjust active issuance proportionally, or set it equal if starting from zero
-            if old_total == 0 {
-                self.active_issuance = amount;
-            } else {
-                // Keep the same ratio of active to total, or cap at new total
-                self.active_issuance = self.active_issuance.min(amount);
-            }
+            let slashed = Self::take_from_reserves(&mut reserves, Some(id), amount);
+            account.reserved = account.reserved.saturating_sub(slashed);
 
-            Ok(())
+            self.store_reserves(who, &reserves);
+            let credit = self.finish_slash(who, account, holds, slashed)?;
+            Ok((credit, amount.saturating_sub(slashed)))
         }
 
-        /// Deactivate some issuance
-        /// This removes the amount from active circulation but keeps it in total issuance
+        /// Move funds reserved under an identifier to the free balance of another account,
+        /// or to its reserve under the same identifier
+        /// Returns the amount that could not be moved
+        /// Origin: root or the `Admin` role
         #[ink(message)]
-        pub fn deactivate(&mut self, amount: Balance) -> Result<()> {
-            let caller = self.env().caller();
-            if caller != self.owner {
-                return Err(Error::NotAllowed);
+        pub fn repatriate_reserved_named(
+            &mut self,
+            id: ReserveIdentifier,
+            from: AccountId,
+            to: AccountId,
+            amount: Balance,
+            status: BalanceStatus,
+        ) -> Result<Balance> {
+            self.ensure_role(Role::Admin)?;
+
+            if from == to {
+                return match status {
+                    BalanceStatus::Free => {
+                        let unreserved = self.unreserve_named(id, from, amount)?;
+                        Ok(amount.saturating_sub(unreserved))
+                    }
+                    BalanceStatus::Reserved => {
+                        Ok(amount.saturating_sub(self.reserved_balance_named(id, from)))
+                    }
+                };
             }
 
-            // Saturating subtraction - can't go below zero
-            self.active_issuance = self.active_issuance.saturating_sub(amount);
-            Ok(())
-        }
+            let mut to_account = self.account(to);
+            if to_account.free.saturating_add(to_account.reserved) == 0 {
+                return Err(Error::DeadAccount);
+            }
 
-        /// Reactivate some issuance
-        /// This adds the amount back to active circulation, capped at total issuance
-        #[ink(message)]
-        pub fn reactivate(&mut self, amount: Balance) -> Result<()> {
-            let caller = self.env().caller();
-            if caller != self.owner {
-                return Err(Error::NotAllowed);
+            let mut from_account = self.account(from);
+            let mut from_reserves = self.reserves.get(from).unwrap_or_default();
+            let mut to_reserves = self.reserves.get(to).unwrap_or_default();
+            let actual = amount.min(self.reserved_balance_named(id, from));
+
+            match status {
+                BalanceStatus::Free => {
+                    to_account.free = to_account.free.checked_add(actual).ok_or(Error::Overflow)?
+                }
+                BalanceStatus::Reserved => {
+                    to_account.reserved = to_account
+                        .reserved
+                        .checked_add(actual)
+                        .ok_or(Error::Overflow)?;
+                    if actual > 0 {
+                        Self::add_to_reserve(&mut to_reserves, id, actual, self.max_reserves)?;
+                    }
+                }
             }
+            Self::take_from_reserves(&mut from_reserves, Some(id), actual);
+            from_account.reserved = from_account.reserved.saturating_sub(actual);
 
-            // Add to active issuance but cap at total issuance
-            self.active_issuance = self
-                .active_issuance
-                .saturating_add(amount)
-                .min(self.total_issuance);
-            Ok(())
+            self.store_reserves(from, &from_reserves);
+            self.store_reserves(to, &to_reserves);
+            self.store_account(NATIVE_ASSET, from, &from_account);
+            self.store_account(NATIVE_ASSET, to, &to_account);
+
+            self.env().emit_event(ReserveRepatriated {
+                from,
+                to,
+                amount: actual,
+                destination_status: status,
+            });
+
+            Ok(amount.saturating_sub(actual))
         }
 
-        /// Mint new tokens to an account (only owner)
-        #[ink(message)]
-        pub fn mint(&mut self, to: AccountId, amount: Balance) -> Result<()> {
-            let caller = self.env().caller();
-            if caller != self.owner {
-                return Err(Error::NotAllowed);
+        /// Add an amount to the named reserve, respecting the reserve limit
+        fn add_to_reserve(
+            reserves: &mut Vec<ReserveData>,
+            id: ReserveIdentifier,
+            amount: Balance,
+            max_reserves: u32,
+        ) -> Result<()> {
+            match reserves.iter_mut().find(|reserve| reserve.id == id) {
+                Some(reserve) => {
+                    reserve.amount = reserve.amount.checked_add(amount).ok_or(Error::Overflow)?;
+                }
+                None => {
+                    if reserves.len() >= max_reserves as usize {
+                        return Err(Error::TooManyReserves);
+                    }
+                    reserves.push(ReserveData { id, amount });
+                }
             }
+            Ok(())
+        }
 
-            self.mint_into(to, amount)
+        /// Take up to `amount` from the named reserves, optionally only from the given identifier
+        fn take_from_reserves(
+            reserves: &mut Vec<ReserveData>,
+            id: Option<ReserveIdentifier>,
+            amount: Balance,
+        ) -> Balance {
+            let mut remaining = amount;
+            for reserve in reserves
+                .iter_mut()
+                .filter(|reserve| id.is_none_or(|id| reserve.id == id))
+            {
+                let taken = remaining.min(reserve.amount);
+                reserve.amount = reserve.amount.saturating_sub(taken);
+                remaining = remaining.saturating_sub(taken);
+            }
+            reserves.retain(|reserve| reserve.amount > 0);
+            amount.saturating_sub(remaining)
         }
 
-        /// Internal mint function
-        fn mint_into(&mut self, to: AccountId, amount: Balance) -> Result<()> {
-            if amount == 0 {
-                return Ok(());
+        /// Store the named reserves of an account, removing the entry once there are none left
+        fn store_reserves(&mut self, who: AccountId, reserves: &Vec<ReserveData>) {
+            if reserves.is_empty() {
+                self.reserves.remove(who);
+            } else {
+                self.reserves.insert(who, reserves);
             }
+        }
 
-            self.total_issuance = self
-                .total_issuance
-                .checked_add(amount)
-                .ok_or(Error::Overflow)?;
-            self.active_issuance = self
-                .active_issuance
-                .checked_add(amount)
-                .ok_or(Error::Overflow)?;
+        /// Get the part of the reserved balance that is neither held nor named
+        fn anonymous_reserve(
+            account: &AccountData,
+            holds: &[IdAmount],
+            reserves: &[ReserveData],
+        ) -> Balance {
+            let held = holds
+                .iter()
+                .fold(0, |total: Balance, hold| total.saturating_add(hold.amount));
+            let named = reserves.iter().fold(0, |total: Balance, reserve| {
+                total.saturating_add(reserve.amount)
+            });
+            account.reserved.saturating_sub(held).saturating_sub(named)
+        }
 
-            let mut account = self.account(to);
+        /// Take up to `amount` from the holds, optionally only from the given reason
+        fn take_from_holds(
+            holds: &mut Vec<IdAmount>,
+            reason: Option<HoldReason>,
+            amount: Balance,
+        ) -> Balance {
+            let mut remaining = amount;
+            for hold in holds
+                .iter_mut()
+                .filter(|hold| reason.is_none_or(|reason| hold.id == reason))
+            {
+                let taken = remaining.min(hold.amount);
+                hold.amount = hold.amount.saturating_sub(taken);
+                remaining = remaining.saturating_sub(taken);
+            }
+            holds.retain(|hold| hold.amount > 0);
+            amount.saturating_sub(remaining)
+        }
 
-            // Check minimum balance for new accounts
-            if account.free == 0 && amount < self.existential_deposit {
-                self.total_issuance = self.total_issuance.saturating_sub(amount);
-                self.active_issuance = self.active_issuance.saturating_sub(amount);
-                return Err(Error::ExistentialDeposit);
+        /// Store a slashed account and its holds, returning the slashed funds as a credit
+        fn finish_slash(
+            &mut self,
+            who: AccountId,
+            mut account: AccountData,
+            holds: Vec<IdAmount>,
+            slashed: Balance,
+        ) -> Result<CreditImbalance> {
+            if slashed == 0 {
+                return Ok(CreditImbalance::zero());
             }
 
-            account.free = account.free.checked_add(amount).ok_or(Error::Overflow)?;
+            self.store_holds(NATIVE_ASSET, who, &holds);
 
-            self.accounts.insert(to, &account);
+            // The account is reaped if what is left is below the existential deposit
+            if account.free > 0
+                && account.free.saturating_add(account.reserved) < self.existential_deposit()
+            {
+                self.collect_dust(NATIVE_ASSET, who, &mut account)?;
+            } else {
+                self.store_account(NATIVE_ASSET, who, &account);
+            }
 
-            self.env().emit_event(Endowed {
-                account: to,
-                free_balance: account.free,
+            self.env().emit_event(Slashed {
+                who,
+                amount: slashed,
             });
 
-            Ok(())
+            Ok(self.new_credit(slashed, 0))
         }
 
-        /// Burn tokens from an account
+        /// Set a lock on an account, applying to every kind of withdrawal
+        /// Origin: trusted caller
         #[ink(message)]
-        pub fn burn_from(
+        pub fn set_lock(&mut self, who: AccountId, id: [u8; 8], amount: Balance) -> Result<()> {
+            self.set_lock_with_reasons(who, id, amount, WithdrawReasons::all())
+        }
+
+        /// Set a lock on an account, applying only to the given kinds of withdrawal
+        /// A lock with a zero amount or without any reasons is removed
+        /// Origin: trusted caller
+        #[ink(message)]
+        pub fn set_lock_with_reasons(
             &mut self,
             who: AccountId,
+            id: [u8; 8],
             amount: Balance,
-            preservation: Preservation,
-            precision: Precision,
-            force: Fortitude,
-        ) -> Result<Balance> {
-            if amount == 0 {
-                return Ok(0);
+            reasons: WithdrawReasons,
+        ) -> Result<()> {
+            self.ensure_trusted()?;
+            if amount == 0 || reasons.is_empty() {
+                return self.remove_lock(who, id);
             }
 
-            let mut account = self.account(who);
-            let reducible = self.reducible_balance(who, preservation, force);
-
-            let actual_burn = match precision {
-                Precision::Exact => {
-                    if reducible < amount {
-                        // Check if this is due to preservation requirements
-                        let usable = account.free.saturating_sub(account.frozen);
-                        if usable >= amount {
-                            // We have the balance, but preservation prevents it
-                            return Err(Error::Expendability);
-                        }
-                        return Err(Error::InsufficientBalance);
+            let mut locks = self.locks.get(who).unwrap_or_default();
+            match locks.iter_mut().find(|lock| lock.id == id) {
+                Some(lock) => {
+                    lock.amount = amount;
+                    lock.reasons = reasons;
+                }
+                None => {
+                    if locks.len() >= self.max_locks as usize {
+                        return Err(Error::TooManyLocks);
                     }
-                    amount
+                    locks.push(BalanceLock {
+                        id,
+                        amount,
+                        reasons,
+                    });
                 }
-                Precision::BestEffort => amount.min(reducible),
-            };
-
-            if actual_burn == 0 {
-                return Ok(0);
             }
 
-            // Check if the burn would violate preservation BEFORE doing it
-            let new_balance = account
-                .free
-                .checked_sub(actual_burn)
-                .ok_or(Error::InsufficientBalance)?;
-
-            account.free = new_balance;
+            self.update_locks(who, locks);
+            Ok(())
+        }
 
-            // Handle dust for Expendable case only
-            // Note: The preservation check above ensures we only reach here with Expendable
-            // when dust needs handling
-            if account.free < self.existential_deposit && account.free > 0 {
-                self.handle_dust(who, &mut account)?;
+        /// Extend a lock to at least the given amount and to the union of the reasons
+        /// A lock is created if none exists; a zero amount or empty reasons change nothing
+        /// Origin: trusted caller
+        #[ink(message)]
+        pub fn extend_lock(
+            &mut self,
+            who: AccountId,
+            id: [u8; 8],
+            amount: Balance,
+            reasons: WithdrawReasons,
+        ) -> Result<()> {
+            self.ensure_trusted()?;
+            if amount == 0 || reasons.is_empty() {
+                return Ok(());
             }
 
-            self.total_issuance = self.total_issuance.saturating_sub(actual_burn);
-            self.active_issuance = self.active_issuance.saturating_sub(actual_burn);
-            self.accounts.insert(who, &account);
+            let mut locks = self.locks.get(who).unwrap_or_default();
+            match locks.iter_mut().find(|lock| lock.id == id) {
+                Some(lock) => {
+                    lock.amount = lock.amount.max(amount);
+                    lock.reasons = lock.reasons | reasons;
+                }
+                None => {
+                    if locks.len() >= self.max_locks as usize {
+                        return Err(Error::TooManyLocks);
+                    }
+                    locks.push(BalanceLock {
+                        id,
+                        amount,
+                        reasons,
+                    });
+                }
+            }
 
-            self.env().emit_event(Burned {
-                who,
-                amount: actual_burn,
-            });
+            self.update_locks(who, locks);
+            Ok(())
+        }
 
-            Ok(actual_burn)
+        /// Remove a lock from an account
+        /// Origin: trusted caller
+        #[ink(message)]
+        pub fn remove_lock(&mut self, who: AccountId, id: [u8; 8]) -> Result<()> {
+            self.ensure_trusted()?;
+            let mut locks = self.locks.get(who).unwrap_or_default();
+            locks.retain(|lock| lock.id != id);
+            self.update_locks(who, locks);
+            Ok(())
         }
 
-        /// Restore balance (mint without ED check, typically used for system operations)
+        /// Get all locks on an account
         #[ink(message)]
-        pub fn restore(&mut self, who: AccountId, amount: Balance) -> Result<()> {
-            if amount == 0 {
-                return Ok(());
-            }
+        pub fn locks(&self, who: AccountId) -> Vec<BalanceLock> {
+            self.locks.get(who).unwrap_or_default()
+        }
 
+        /// Store the locks of an account and recompute its frozen balance
+        /// Emits `Locked` or `Unlocked` for every lock whose amount changed
+        fn update_locks(&mut self, who: AccountId, locks: Vec<BalanceLock>) {
+            let old_locks = self.locks.get(who).unwrap_or_default();
+            let freezes = self.freezes.get((NATIVE_ASSET, who)).unwrap_or_default();
             let mut account = self.account(who);
+            account.frozen = Self::frozen_amount(&locks, &freezes);
 
-            // Check minimum balance for new accounts
-            if account.free == 0 && amount < self.existential_deposit {
-                return Err(Error::ExistentialDeposit);
+            if locks.is_empty() {
+                self.locks.remove(who);
+            } else {
+                self.locks.insert(who, &locks);
             }
+            self.store_frozen_account(NATIVE_ASSET, who, &account);
 
-            self.total_issuance = self
-                .total_issuance
-                .checked_add(amount)
-                .ok_or(Error::Overflow)?;
-            self.active_issuance = self
-                .active_issuance
-                .checked_add(amount)
-                .ok_or(Error::Overflow)?;
+            let amount_of = |locks: &[BalanceLock], id: [u8; 8]| {
+                locks
+                    .iter()
+                    .find(|lock| lock.id == id)
+                    .map_or(0, |lock| lock.amount)
+            };
+            // Removed locks followed by the locks that remain or were added
+            let removed = old_locks
+                .iter()
+                .filter(|old| locks.iter().all(|lock| lock.id != old.id));
+            for id in removed.chain(locks.iter()).map(|lock| lock.id) {
+                let old = amount_of(&old_locks, id);
+                let new = amount_of(&locks, id);
+                if new > old {
+                    self.env().emit_event(Locked {
+                        who,
+                        id,
+                        amount: new.saturating_sub(old),
+                    });
+                } else if new < old {
+                    self.env().emit_event(Unlocked {
+                        who,
+                        id,
+                        amount: old.saturating_sub(new),
+                    });
+                }
+            }
+        }
 
-            account.free = account.free.checked_add(amount).ok_or(Error::Overflow)?;
+        /// Get the amount frozen on an account for a specific reason
+        #[ink(message)]
+        pub fn balance_frozen(&self, reason: FreezeReason, who: AccountId) -> Balance {
+            self.asset_balance_frozen(NATIVE_ASSET, reason, who)
+        }
 
-            self.accounts.insert(who, &account);
+        /// Get the amount of an asset frozen on an account for a specific reason
+        #[ink(message)]
+        pub fn asset_balance_frozen(
+            &self,
+            asset: AssetId,
+            reason: FreezeReason,
+            who: AccountId,
+        ) -> Balance {
+            self.freezes
+                .get((asset, who))
+                .unwrap_or_default()
+                .iter()
+                .find(|freeze| freeze.id == reason)
+                .map_or(0, |freeze| freeze.amount)
+        }
 
-            self.env().emit_event(Restored { who, amount });
+        /// Check if a freeze for the given reason can be placed or updated on an account
+        #[ink(message)]
+        pub fn can_freeze(&self, reason: FreezeReason, who: AccountId) -> bool {
+            self.asset_can_freeze(NATIVE_ASSET, reason, who)
+        }
 
-            Ok(())
+        /// Check if a freeze of an asset for the given reason can be placed or updated
+        #[ink(message)]
+        pub fn asset_can_freeze(
+            &self,
+            asset: AssetId,
+            reason: FreezeReason,
+            who: AccountId,
+        ) -> bool {
+            let freezes = self.freezes.get((asset, who)).unwrap_or_default();
+            freezes.len() < self.max_freezes as usize
+                || freezes.iter().any(|freeze| freeze.id == reason)
         }
 
-        /// Shelve balance (burn without affecting total issuance tracking)
+        /// Set the frozen amount for a reason, replacing any previous amount
+        /// Setting a zero amount removes the freeze
+        /// Origin: trusted caller
         #[ink(message)]
-        pub fn shelve(&mut self, who: AccountId, amount: Balance) -> Result<()> {
+        pub fn set_freeze(
+            &mut self,
+            reason: FreezeReason,
+            who: AccountId,
+            amount: Balance,
+        ) -> Result<()> {
+            self.asset_set_freeze(NATIVE_ASSET, reason, who, amount)
+        }
+
+        /// Set the frozen amount of an asset for a reason, replacing any previous amount
+        /// Origin: trusted caller
+        #[ink(message)]
+        pub fn asset_set_freeze(
+            &mut self,
+            asset: AssetId,
+            reason: FreezeReason,
+            who: AccountId,
+            amount: Balance,
+        ) -> Result<()> {
+            self.ensure_trusted()?;
             if amount == 0 {
-                return Ok(());
+                return self.asset_thaw(asset, reason, who);
             }
+            self.ensure_asset(asset)?;
 
-            let mut account = self.account(who);
-
-            if account.free < amount {
-                return Err(Error::InsufficientBalance);
+            let mut freezes = self.freezes.get((asset, who)).unwrap_or_default();
+            match freezes.iter_mut().find(|freeze| freeze.id == reason) {
+                Some(freeze) => freeze.amount = amount,
+                None => {
+                    if freezes.len() >= self.max_freezes as usize {
+                        return Err(Error::TooManyFreezes);
+                    }
+                    freezes.push(IdAmount { id: reason, amount });
+                }
             }
 
-            account.free = account
-                .free
-                .checked_sub(amount)
-                .ok_or(Error::InsufficientBalance)?;
-
-            self.total_issuance = self.total_issuance.saturating_sub(amount);
-            self.active_issuance = self.active_issuance.saturating_sub(amount);
-            self.accounts.insert(who, &account);
-
-            self.env().emit_event(Shelved { who, amount });
-
+            self.update_freezes(asset, who, freezes);
             Ok(())
         }
 
-        /// Set balance directly (combines mint/burn as needed)
+        /// Extend the frozen amount for a reason to at least the given amount
+        /// Origin: trusted caller
         #[ink(message)]
-        pub fn set_balance(&mut self, who: AccountId, amount: Balance) -> Balance {
-            let mut account = self.account(who);
-            let current = account.free;
+        pub fn extend_freeze(
+            &mut self,
+            reason: FreezeReason,
+            who: AccountId,
+            amount: Balance,
+        ) -> Result<()> {
+            self.asset_extend_freeze(NATIVE_ASSET, reason, who, amount)
+        }
 
-            if amount == current {
-                return amount;
+        /// Extend the frozen amount of an asset for a reason to at least the given amount
+        /// Origin: trusted caller
+        #[ink(message)]
+        pub fn asset_extend_freeze(
+            &mut self,
+            asset: AssetId,
+            reason: FreezeReason,
+            who: AccountId,
+            amount: Balance,
+        ) -> Result<()> {
+            self.ensure_trusted()?;
+            self.ensure_asset(asset)?;
+            if amount == 0 {
+                return Ok(());
             }
 
-            if amount > current {
-                // Mint
-                let to_mint = amount.saturating_sub(current);
-                if let Some(new_issuance) = self.total_issuance.checked_add(to_mint) {
-                    if let Some(new_balance) = account.free.checked_add(to_mint) {
-                        self.total_issuance = new_issuance;
-                        self.active_issuance = self.active_issuance.saturating_add(to_mint);
-                        account.free = new_balance;
-                        self.accounts.insert(who, &account);
-                        return new_balance;
+            let mut freezes = self.freezes.get((asset, who)).unwrap_or_default();
+            match freezes.iter_mut().find(|freeze| freeze.id == reason) {
+                Some(freeze) => freeze.amount = freeze.amount.max(amount),
+                None => {
+                    if freezes.len() >= self.max_freezes as usize {
+                        return Err(Error::TooManyFreezes);
                     }
-                }
-            } else {
-                // Burn
-                let to_burn = current.saturating_sub(amount);
-                if let Some(new_balance) = account.free.checked_sub(to_burn) {
-                    self.total_issuance = self.total_issuance.saturating_sub(to_burn);
-                    self.active_issuance = self.active_issuance.saturating_sub(to_burn);
-                    account.free = new_balance;
-                    self.accounts.insert(who, &account);
-                    return new_balance;
+                    freezes.push(IdAmount { id: reason, amount });
                 }
             }
 
-            current
+            self.update_freezes(asset, who, freezes);
+            Ok(())
         }
 
-        /// Transfer tokens from caller to another account
+        /// Increase the frozen amount for a reason
+        /// Origin: trusted caller
         #[ink(message)]
-        pub fn transfer(&mut self, to: AccountId, amount: Balance) -> Result<()> {
-            let from = self.env().caller();
-            self.do_transfer(from, to, amount, Preservation::Expendable)
+        pub fn increase_frozen(
+            &mut self,
+            reason: FreezeReason,
+            who: AccountId,
+            amount: Balance,
+        ) -> Result<()> {
+            self.asset_increase_frozen(NATIVE_ASSET, reason, who, amount)
         }
 
-        /// Transfer keeping the sender alive (won't go below ED)
+        /// Increase the frozen amount of an asset for a reason
+        /// Origin: trusted caller
         #[ink(message)]
-        pub fn transfer_keep_alive(&mut self, to: AccountId, amount: Balance) -> Result<()> {
-            let from = self.env().caller();
-            self.do_transfer(from, to, amount, Preservation::Preserve)
+        pub fn asset_increase_frozen(
+            &mut self,
+            asset: AssetId,
+            reason: FreezeReason,
+            who: AccountId,
+            amount: Balance,
+        ) -> Result<()> {
+            self.ensure_trusted()?;
+            let frozen = self
+                .asset_balance_frozen(asset, reason, who)
+                .saturating_add(amount);
+            self.asset_set_freeze(asset, reason, who, frozen)
         }
 
-        /// Transfer with explicit preservation mode
+        /// Decrease the frozen amount for a reason, removing the freeze if it reaches zero
+        /// Origin: trusted caller
         #[ink(message)]
-        pub fn transfer_with_preservation(
+        pub fn decrease_frozen(
             &mut self,
-            to: AccountId,
+            reason: FreezeReason,
+            who: AccountId,
             amount: Balance,
-            preservation: Preservation,
         ) -> Result<()> {
-            let from = self.env().caller();
-            self.do_transfer(from, to, amount, preservation)
+            self.asset_decrease_frozen(NATIVE_ASSET, reason, who, amount)
         }
 
-        /// Internal transfer function
-        fn do_transfer(
+        /// Decrease the frozen amount of an asset for a reason
+        /// Origin: trusted caller
+        #[ink(message)]
+        pub fn asset_decrease_frozen(
             &mut self,
-            from: AccountId,
-            to: AccountId,
+            asset: AssetId,
+            reason: FreezeReason,
+            who: AccountId,
             amount: Balance,
-            preservation: Preservation,
         ) -> Result<()> {
-            if amount == 0 {
-                return Ok(());
-            }
+            self.ensure_trusted()?;
+            let frozen = self
+                .asset_balance_frozen(asset, reason, who)
+                .saturating_sub(amount);
+            self.asset_set_freeze(asset, reason, who, frozen)
+        }
 
-            // Handle self-transfer as a no-op
-            if from == to {
-                self.env().emit_event(Transfer {
-                    from: Some(from),
-                    to: Some(to),
-                    value: amount,
-                });
-                return Ok(());
-            }
+        /// Remove the freeze for a reason
+        /// Origin: trusted caller
+        #[ink(message)]
+        pub fn thaw(&mut self, reason: FreezeReason, who: AccountId) -> Result<()> {
+            self.asset_thaw(NATIVE_ASSET, reason, who)
+        }
 
-            let mut from_account = self.account(from);
-            let mut to_account = self.account(to);
+        /// Remove the freeze of an asset for a reason
+        /// Origin: trusted caller
+        #[ink(message)]
+        pub fn asset_thaw(
+            &mut self,
+            asset: AssetId,
+            reason: FreezeReason,
+            who: AccountId,
+        ) -> Result<()> {
+            self.ensure_trusted()?;
+            self.ensure_asset(asset)?;
+            let mut freezes = self.freezes.get((asset, who)).unwrap_or_default();
+            freezes.retain(|freeze| freeze.id != reason);
+            self.update_freezes(asset, who, freezes);
+            Ok(())
+        }
 
-            // Check if sender has enough usable balance
-            let usable = from_account.free.saturating_sub(from_account.frozen);
-            if usable < amount {
-                return Err(Error::LiquidityRestrictions);
-            }
+        /// Store the freezes of an account and recompute its frozen balance
+        fn update_freezes(&mut self, asset: AssetId, who: AccountId, freezes: Vec<IdAmount>) {
+            let locks = if asset == NATIVE_ASSET {
+                self.locks.get(who).unwrap_or_default()
+            } else {
+                Vec::new()
+            };
+            let mut account = self.asset_account(asset, who);
+            let old_frozen = account.frozen;
+            account.frozen = Self::frozen_amount(&locks, &freezes);
 
-            // Check preservation mode
-            match preservation {
-                Preservation::Preserve | Preservation::Protect => {
-                    let new_from_balance = from_account
-                        .free
-                        .checked_sub(amount)
-                        .ok_or(Error::InsufficientBalance)?;
-                    if new_from_balance < self.existential_deposit {
-                        return Err(Error::Expendability);
-                    }
-                }
-                Preservation::Expendable => {
-                    // Allow going to zero
-                }
+            if freezes.is_empty() {
+                self.freezes.remove((asset, who));
+            } else {
+                self.freezes.insert((asset, who), &freezes);
             }
+            self.store_frozen_account(asset, who, &account);
 
-            // Perform transfer
-            from_account.free = from_account
-                .free
-                .checked_sub(amount)
-                .ok_or(Error::InsufficientBalance)?;
-            to_account.free = to_account.free.checked_add(amount).ok_or(Error::Overflow)?;
+            if account.frozen > old_frozen {
+                self.env().emit_event(Frozen {
+                    asset,
+                    who,
+                    amount: account.frozen.saturating_sub(old_frozen),
+                });
+            } else if account.frozen < old_frozen {
+                self.env().emit_event(Thawed {
+                    asset,
+                    who,
+                    amount: old_frozen.saturating_sub(account.frozen),
+                });
+            }
+        }
 
-            // Handle dust for Expendable mode only
-            // Note: The preservation check above ensures we only reach here with dust
-            // when preservation is Expendable
-            if from_account.free < self.existential_deposit && from_account.free > 0 {
-                self.handle_dust(from, &mut from_account)?;
+        /// Store an account whose locks or freezes changed
+        /// An account without balance is kept while some of it is frozen, so the restriction
+        /// still applies once it is funded, otherwise it is written through `store_account`
+        fn store_frozen_account(&mut self, asset: AssetId, who: AccountId, account: &AccountData) {
+            if account.frozen > 0 {
+                self.accounts.insert((asset, who), account);
+            } else {
+                self.store_account(asset, who, account);
             }
+        }
 
-            self.accounts.insert(from, &from_account);
-            self.accounts.insert(to, &to_account);
+        /// Frozen balance implied by a set of locks and freezes: the largest of them
+        fn frozen_amount(locks: &[BalanceLock], freezes: &[IdAmount]) -> Balance {
+            locks
+                .iter()
+                .map(|lock| lock.amount)
+                .chain(freezes.iter().map(|freeze| freeze.amount))
+                .max()
+                .unwrap_or(0)
+        }
 
-            self.env().emit_event(Transfer {
-                from: Some(from),
-                to: Some(to),
-                value: amount,
-            });
+        /// Set the dust trap account, or burn dust when there is none
+        /// Origin: root or the `DustManager` role
+        #[ink(message)]
+        pub fn set_dust_trap(&mut self, dust_trap: Option<AccountId>) -> Result<()> {
+            self.set_dust_policy(dust_trap.map_or(DustPolicy::Burn, DustPolicy::Trap))
+        }
 
+        /// Set the dust policy
+        /// Dust already collected in the pot stays there until it is swept
+        /// Origin: root or the `DustManager` role
+        #[ink(message)]
+        pub fn set_dust_policy(&mut self, dust_policy: DustPolicy) -> Result<()> {
+            self.ensure_role(Role::DustManager)?;
+            self.dust_policy = dust_policy;
             Ok(())
         }
 
-        /// Handle dust collection
-        fn handle_dust(&mut self, who: AccountId, account: &mut AccountData) -> Result<()> {
-            let dust_amount = account.free;
+        /// Move the dust collected for an asset to the free balance of an account
+        /// Returns the amount swept
+        /// Origin: root or the `DustManager` role
+        #[ink(message)]
+        pub fn sweep_dust(&mut self, asset: AssetId, to: AccountId) -> Result<Balance> {
+            self.ensure_role(Role::DustManager)?;
+            let amount = self.dust_pot(asset);
+            if amount == 0 {
+                return Ok(0);
+            }
 
-            if let Some(dust_trap) = self.dust_trap {
-                // Transfer dust to dust trap
-                let mut trap_account = self.account(dust_trap);
-                trap_account.free = trap_account
-                    .free
-                    .checked_add(dust_amount)
-                    .ok_or(Error::Overflow)?;
-                self.accounts.insert(dust_trap, &trap_account);
-                account.free = 0;
+            // The dust never left total issuance, so it is deposited as existing funds
+            self.ensure_can_deposit(asset, to, amount, Provenance::Extant)?;
+            let mut account = self.asset_account(asset, to);
+            account.free = account.free.saturating_add(amount);
+            self.store_account(asset, to, &account);
+            self.dust_pots.remove(asset);
+            Ok(amount)
+        }
+
+        /// Register or remove a trusted caller
+        /// Trusted callers may move funds on behalf of any account, like a pallet does in FRAME
+        /// Origin: root or the `Admin` role
+        #[ink(message)]
+        pub fn set_trusted(&mut self, who: AccountId, trusted: bool) -> Result<()> {
+            self.ensure_role(Role::Admin)?;
+            if trusted {
+                self.trusted.insert(who, &());
             } else {
-                // Remove dust from total issuance
-                self.env().emit_event(DustLost {
-                    account: who,
-                    amount: dust_amount,
-                });
-                self.total_issuance = self.total_issuance.saturating_sub(dust_amount);
-                self.active_issuance = self.active_issuance.saturating_sub(dust_amount);
-                account.free = 0;
+                self.trusted.remove(who);
             }
-
             Ok(())
         }
 
-        /// Reserve some balance from an account
+        /// Check whether an account is a trusted caller
+        /// The owner is always trusted
         #[ink(message)]
-        pub fn reserve(&mut self, who: AccountId, amount: Balance) -> Result<()> {
-            if amount == 0 {
+        pub fn is_trusted(&self, who: AccountId) -> bool {
+            who == self.owner || self.trusted.contains(who)
+        }
+
+        /// Grant a role to an account
+        /// Origin: root or the `Admin` role
+        #[ink(message)]
+        pub fn grant_role(&mut self, role: Role, account: AccountId) -> Result<()> {
+            self.ensure_role(Role::Admin)?;
+            if self.roles.contains((role, account)) {
                 return Ok(());
             }
+            self.roles.insert((role, account), &());
+            self.env().emit_event(RoleGranted {
+                role,
+                account,
+                sender: self.env().caller(),
+            });
+            Ok(())
+        }
 
-            let mut account = self.account(who);
-            let usable = account.free.saturating_sub(account.frozen);
-
-            if usable < amount {
-                return Err(Error::InsufficientBalance);
-            }
+        /// Revoke a role from an account
+        /// The implicit roles of the owner cannot be revoked
+        /// Origin: root or the `Admin` role
+        #[ink(message)]
+        pub fn revoke_role(&mut self, role: Role, account: AccountId) -> Result<()> {
+            self.ensure_role(Role::Admin)?;
+            self.remove_role(role, account);
+            Ok(())
+        }
 
-            account.free = account
-                .free
-                .checked_sub(amount)
-                .ok_or(Error::InsufficientBalance)?;
-            account.reserved = account
-                .reserved
-                .checked_add(amount)
-                .ok_or(Error::Overflow)?;
+        /// Give up a role held by the caller
+        /// Origin: signed by the role holder
+        #[ink(message)]
+        pub fn renounce_role(&mut self, role: Role) -> Result<()> {
+            let caller = self.env().caller();
+            self.remove_role(role, caller);
+            Ok(())
+        }
 
-            self.accounts.insert(who, &account);
+        /// Remove a granted role, emitting an event if it was held
+        fn remove_role(&mut self, role: Role, account: AccountId) {
+            if !self.roles.contains((role, account)) {
+                return;
+            }
+            self.roles.remove((role, account));
+            self.env().emit_event(RoleRevoked {
+                role,
+                account,
+                sender: self.env().caller(),
+            });
+        }
 
-            self.env().emit_event(Reserved { who, amount });
+        /// Check whether an account holds a role, either granted or as the owner
+        #[ink(message)]
+        pub fn has_role(&self, role: Role, account: AccountId) -> bool {
+            account == self.owner || self.roles.contains((role, account))
+        }
 
+        /// Propose a new owner, replacing any earlier proposal
+        /// Ownership only moves once the proposed account accepts it
+        /// Origin: root
+        #[ink(message)]
+        pub fn propose_owner(&mut self, proposed: AccountId) -> Result<()> {
+            self.ensure_root()?;
+            self.pending_owner = Some(proposed);
+            self.env().emit_event(OwnershipProposed {
+                owner: self.owner,
+                proposed,
+            });
             Ok(())
         }
 
-        /// Unreserve some balance for an account
+        /// Accept a pending ownership proposal
+        /// The native asset follows the contract owner
+        /// Origin: signed by the proposed owner
         #[ink(message)]
-        pub fn unreserve(&mut self, who: AccountId, amount: Balance) -> Result<Balance> {
-            if amount == 0 {
-                return Ok(0);
+        pub fn accept_ownership(&mut self) -> Result<()> {
+            let caller = self.env().caller();
+            if self.pending_owner != Some(caller) {
+                return Err(Error::NotAllowed);
             }
 
-            let mut account = self.account(who);
-            let actual = amount.min(account.reserved);
-
-            account.reserved = account.reserved.saturating_sub(actual);
-            account.free = account.free.checked_add(actual).ok_or(Error::Overflow)?;
+            let previous = self.owner;
+            self.owner = caller;
+            self.pending_owner = None;
 
-            self.accounts.insert(who, &account);
+            let mut native = self.details(NATIVE_ASSET);
+            native.owner = caller;
+            self.assets.insert(NATIVE_ASSET, &native);
 
-            self.env().emit_event(Unreserved {
-                who,
-                amount: actual,
+            self.env().emit_event(OwnershipTransferred {
+                previous,
+                owner: caller,
             });
-
-            Ok(actual)
+            Ok(())
         }
 
-        /// Set a lock on an account
+        /// Get the contract owner
         #[ink(message)]
-        pub fn set_lock(&mut self, who: AccountId, id: [u8; 8], amount: Balance) -> Result<()> {
-            let mut locks = self.locks.get(who).unwrap_or_default();
-
-            // Find existing lock or add new one
-            let mut found = false;
-            for lock in locks.iter_mut() {
-                if lock.id == id {
-                    lock.amount = amount;
-                    found = true;
-                    break;
-                }
-            }
-
-            if !found {
-                if locks.len() >= self.max_locks as usize {
-                    return Err(Error::TooManyLocks);
-                }
-                locks.push(BalanceLock { id, amount });
-            }
-
-            // Update frozen amount
-            let max_lock = locks.iter().map(|l| l.amount).max().unwrap_or(0);
-            let mut account = self.account(who);
-            let old_frozen = account.frozen;
-            account.frozen = max_lock;
+        pub fn owner(&self) -> AccountId {
+            self.owner
+        }
 
-            self.locks.insert(who, &locks);
-            self.accounts.insert(who, &account);
+        /// Get the account proposed as the next owner, if any
+        #[ink(message)]
+        pub fn pending_owner(&self) -> Option<AccountId> {
+            self.pending_owner
+        }
 
-            if account.frozen > old_frozen {
-                self.env().emit_event(Locked {
-                    who,
-                    amount: account.frozen.saturating_sub(old_frozen),
-                });
+        /// Change or remove the cap on the total issuance
+        /// The cap cannot be set below the current total issuance
+        /// Origin: root
+        #[ink(message)]
+        pub fn set_max_supply(&mut self, max_supply: Option<Balance>) -> Result<()> {
+            self.ensure_root()?;
+            if max_supply.is_some_and(|max_supply| max_supply < self.total_issuance()) {
+                return Err(Error::MaxSupplyExceeded);
             }
-
+            self.max_supply = max_supply;
             Ok(())
         }
 
-        /// Remove a lock from an account
+        /// Get the cap on the total issuance, if any
         #[ink(message)]
-        pub fn remove_lock(&mut self, who: AccountId, id: [u8; 8]) -> Result<()> {
-            let mut locks = self.locks.get(who).unwrap_or_default();
-            locks.retain(|lock| lock.id != id);
-
-            let max_lock = locks.iter().map(|l| l.amount).max().unwrap_or(0);
-            let mut account = self.account(who);
-            let old_frozen = account.frozen;
-            account.frozen = max_lock;
-
-            if locks.is_empty() {
-                self.locks.remove(who);
-            } else {
-                self.locks.insert(who, &locks);
-            }
-            self.accounts.insert(who, &account);
+        pub fn max_supply(&self) -> Option<Balance> {
+            self.max_supply
+        }
 
-            if old_frozen > account.frozen {
-                self.env().emit_event(Unlocked {
-                    who,
-                    amount: old_frozen.saturating_sub(account.frozen),
-                });
+        /// Get the dust trap account, if dust is sent to one
+        #[ink(message)]
+        pub fn dust_trap(&self) -> Option<AccountId> {
+            match self.dust_policy {
+                DustPolicy::Trap(trap) => Some(trap),
+                _ => None,
             }
-
-            Ok(())
         }
 
-        /// Set the dust trap account
+        /// Get the dust policy
         #[ink(message)]
-        pub fn set_dust_trap(&mut self, dust_trap: Option<AccountId>) -> Result<()> {
-            let caller = self.env().caller();
-            if caller != self.owner {
-                return Err(Error::NotAllowed);
-            }
-            self.dust_trap = dust_trap;
-            Ok(())
+        pub fn dust_policy(&self) -> DustPolicy {
+            self.dust_policy
         }
 
-        /// Get the dust trap account
+        /// Get the dust collected in the pot for an asset
         #[ink(message)]
-        pub fn dust_trap(&self) -> Option<AccountId> {
-            self.dust_trap
+        pub fn dust_pot(&self, asset: AssetId) -> Balance {
+            self.dust_pots.get(asset).unwrap_or(0)
         }
     }
 
@@ -1169,6 +4870,36 @@ mod balances_contract {
         include!("conformance_tests\\regular_unbalanced.in.rs");
         #[cfg(windows)]
         include!("conformance_tests\\additional_coverage.in.rs");
+        #[cfg(windows)]
+        include!("conformance_tests\\inspect_mutate_hold.in.rs");
+        #[cfg(windows)]
+        include!("conformance_tests\\inspect_mutate_freeze.in.rs");
+        #[cfg(windows)]
+        include!("conformance_tests\\imbalances.in.rs");
+        #[cfg(windows)]
+        include!("conformance_tests\\account_refs.in.rs");
+        #[cfg(windows)]
+        include!("conformance_tests\\slashing.in.rs");
+        #[cfg(windows)]
+        include!("conformance_tests\\currency.in.rs");
+        #[cfg(windows)]
+        include!("conformance_tests\\named_reserves.in.rs");
+        #[cfg(windows)]
+        include!("conformance_tests\\assets.in.rs");
+        #[cfg(windows)]
+        include!("conformance_tests\\origins.in.rs");
+        #[cfg(windows)]
+        include!("conformance_tests\\roles.in.rs");
+        #[cfg(windows)]
+        include!("conformance_tests\\minter_quotas.in.rs");
+        #[cfg(windows)]
+        include!("conformance_tests\\max_supply.in.rs");
+        #[cfg(windows)]
+        include!("conformance_tests\\inactive_issuance.in.rs");
+        #[cfg(windows)]
+        include!("conformance_tests\\dust_policy.in.rs");
+        #[cfg(windows)]
+        include!("conformance_tests\\reaping.in.rs");
 
         #[cfg(not(windows))]
         include!("conformance_tests/inspect_mutate.in.rs");
@@ -1180,5 +4911,35 @@ mod balances_contract {
         include!("conformance_tests/regular_unbalanced.in.rs");
         #[cfg(not(windows))]
         include!("conformance_tests/additional_coverage.in.rs");
+        #[cfg(not(windows))]
+        include!("conformance_tests/inspect_mutate_hold.in.rs");
+        #[cfg(not(windows))]
+        include!("conformance_tests/inspect_mutate_freeze.in.rs");
+        #[cfg(not(windows))]
+        include!("conformance_tests/imbalances.in.rs");
+        #[cfg(not(windows))]
+        include!("conformance_tests/account_refs.in.rs");
+        #[cfg(not(windows))]
+        include!("conformance_tests/slashing.in.rs");
+        #[cfg(not(windows))]
+        include!("conformance_tests/currency.in.rs");
+        #[cfg(not(windows))]
+        include!("conformance_tests/named_reserves.in.rs");
+        #[cfg(not(windows))]
+        include!("conformance_tests/assets.in.rs");
+        #[cfg(not(windows))]
+        include!("conformance_tests/origins.in.rs");
+        #[cfg(not(windows))]
+        include!("conformance_tests/roles.in.rs");
+        #[cfg(not(windows))]
+        include!("conformance_tests/minter_quotas.in.rs");
+        #[cfg(not(windows))]
+        include!("conformance_tests/max_supply.in.rs");
+        #[cfg(not(windows))]
+        include!("conformance_tests/inactive_issuance.in.rs");
+        #[cfg(not(windows))]
+        include!("conformance_tests/dust_policy.in.rs");
+        #[cfg(not(windows))]
+        include!("conformance_tests/reaping.in.rs");
     }
 }